@@ -2,9 +2,15 @@
 pub const PLATFORM_SEED: &[u8] = b"platform";
 pub const CONFIG_SEED: &[u8] = b"config";
 
+/// Platform statistics PDA seed, used with `PLATFORM_SEED`
+pub const STATS_SEED: &[u8] = b"stats";
+
 /// Launch configuration PDA seed
 pub const LAUNCH_SEED: &[u8] = b"launch";
 
+/// Launch metadata PDA seed
+pub const LAUNCH_METADATA_SEED: &[u8] = b"launch_metadata";
+
 /// Investor account PDA seed
 pub const INVESTOR_SEED: &[u8] = b"investor";
 
@@ -14,6 +20,39 @@ pub const TREASURY_SEED: &[u8] = b"treasury";
 /// Vesting account PDA seed
 pub const VESTING_SEED: &[u8] = b"vesting";
 
+/// Referrer account PDA seed
+pub const REFERRER_SEED: &[u8] = b"referrer";
+
+/// Insurance fund PDA seeds
+pub const INSURANCE_SEED: &[u8] = b"insurance";
+pub const INSURANCE_CLAIM_SEED: &[u8] = b"insurance_claim";
+
+/// Milestone escrow PDA seeds
+pub const MILESTONE_SEED: &[u8] = b"milestones";
+pub const MILESTONE_VOTE_SEED: &[u8] = b"milestone_vote";
+
+/// Refund holding PDA seed
+pub const REFUND_HOLDING_SEED: &[u8] = b"refund_holding";
+
+/// Creator profile PDA seed
+pub const CREATOR_PROFILE_SEED: &[u8] = b"creator_profile";
+
+/// Launch registry PDA seed
+pub const LAUNCH_REGISTRY_SEED: &[u8] = b"launch_registry";
+
+/// Contribution ledger PDA seed
+pub const CONTRIBUTION_LEDGER_SEED: &[u8] = b"contribution_ledger";
+
+/// Position NFT mint PDA seed
+pub const POSITION_MINT_SEED: &[u8] = b"position_mint";
+
+/// Stake account PDA seed
+pub const STAKE_SEED: &[u8] = b"stake";
+
+/// Governance PDA seeds
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const PROPOSAL_VOTE_SEED: &[u8] = b"proposal_vote";
+
 /// Maximum string lengths for validation
 pub const MAX_NAME_LENGTH: usize = 50;
 pub const MAX_SYMBOL_LENGTH: usize = 10;
@@ -21,6 +60,46 @@ pub const MAX_DESCRIPTION_LENGTH: usize = 500;
 pub const MAX_URL_LENGTH: usize = 200;
 pub const MAX_SOCIAL_LENGTH: usize = 100;
 
+/// Current account layout versions (accounts created before versioning have no version field)
+pub const PLATFORM_CONFIG_VERSION: u8 = 1;
+pub const LAUNCH_CONFIG_VERSION: u8 = 1;
+pub const INVESTOR_ACCOUNT_VERSION: u8 = 1;
+pub const LAUNCH_METADATA_VERSION: u8 = 1;
+pub const CREATOR_PROFILE_VERSION: u8 = 1;
+pub const LAUNCH_REGISTRY_VERSION: u8 = 1;
+pub const PLATFORM_STATS_VERSION: u8 = 1;
+pub const CONTRIBUTION_LEDGER_VERSION: u8 = 1;
+pub const STAKE_ACCOUNT_VERSION: u8 = 1;
+
+/// Launch IDs per registry page; launch `n` is listed on page `n / REGISTRY_PAGE_SIZE`
+pub const REGISTRY_PAGE_SIZE: u64 = 64;
+
+/// Entries per contribution ledger page; entry `n` is stored on page `n / LEDGER_PAGE_SIZE`
+pub const LEDGER_PAGE_SIZE: u64 = 16;
+
+/// Staking tier limits
+pub const MAX_STAKING_TIERS: usize = 5;
+pub const MAX_ALLOCATION_MULTIPLIER: u16 = 50_000; // 5x the launch max contribution
+pub const MAX_STAKE_LOCK_DURATION: i64 = 365 * SECONDS_PER_DAY;
+
+/// Time before the presale ends at which unused reserved allocations lapse
+pub const RESERVATION_CUTOFF: i64 = 12 * SECONDS_PER_HOUR;
+
+/// Milestone escrow limits
+pub const MAX_MILESTONES: usize = 5;
+pub const MILESTONE_VOTING_PERIOD: i64 = 3 * SECONDS_PER_DAY;
+pub const MILESTONE_QUORUM_BPS: u16 = 2_000; // 20% of raised funds must vote
+
+/// Time after the presale ends before a launch can be closed with open investor accounts
+pub const LAUNCH_SUNSET_PERIOD: i64 = 180 * SECONDS_PER_DAY;
+
+/// Highest transfer fee a Token-2022 launch mint can charge
+pub const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 1_000; // 10%
+
+/// Governance voting period limits
+pub const MIN_GOVERNANCE_VOTING_PERIOD: i64 = SECONDS_PER_DAY;
+pub const MAX_GOVERNANCE_VOTING_PERIOD: i64 = 14 * SECONDS_PER_DAY;
+
 /// Time constants
 pub const SECONDS_PER_DAY: i64 = 86_400;
 pub const SECONDS_PER_HOUR: i64 = 3_600;
@@ -33,6 +112,17 @@ pub const DEFAULT_PLATFORM_FEE: u16 = 250; // 2.5%
 pub const DEFAULT_MIN_LAUNCH_DURATION: i64 = 24 * SECONDS_PER_HOUR; // 24 hours
 pub const DEFAULT_MAX_LAUNCH_DURATION: i64 = 30 * SECONDS_PER_DAY; // 30 days
 pub const DEFAULT_MIN_SOFT_CAP: u64 = 1_000_000_000; // 1 SOL in lamports
+pub const DEFAULT_LISTING_FEE: u64 = 0; // Listing fee disabled
+pub const DEFAULT_CREATOR_BOND: u64 = 0; // Creator bond disabled
+pub const DEFAULT_REFERRAL_FEE_SHARE: u16 = 0; // Referral program disabled
+pub const DEFAULT_INSURANCE_FEE_SHARE: u16 = 0; // Insurance contributions disabled
+pub const DEFAULT_MILESTONE_THRESHOLD: u64 = 0; // Milestones never required
+pub const DEFAULT_REFUND_WINDOW: i64 = 0; // Refunds never swept
+
+/// Default launch governance values
+pub const DEFAULT_GOVERNANCE_QUORUM: u16 = 2_000; // 20% of allocated tokens
+pub const DEFAULT_GOVERNANCE_THRESHOLD: u16 = 5_000; // Simple majority
+pub const DEFAULT_GOVERNANCE_VOTING_PERIOD: i64 = 3 * SECONDS_PER_DAY;
 
 /// Token decimals limits
 pub const MIN_TOKEN_DECIMALS: u8 = 0;
@@ -97,4 +97,184 @@ pub enum LaunchpadError {
     
     #[msg("Launch start time must be before end time")]
     StartTimeAfterEndTime,
-}
\ No newline at end of file
+    
+    #[msg("Investors cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("Referral fee share is invalid")]
+    InvalidReferralShare,
+    
+    #[msg("No referral rewards available")]
+    NoReferralRewards,
+    
+    #[msg("Insurance fee share is invalid")]
+    InvalidInsuranceShare,
+    
+    #[msg("Insurance fund has insufficient uncommitted balance")]
+    InsufficientInsuranceFunds,
+    
+    #[msg("No insurance compensation available")]
+    NoCompensationAvailable,
+    
+    #[msg("Milestone schedule is invalid")]
+    InvalidMilestoneSchedule,
+    
+    #[msg("Launch hard cap requires a milestone schedule")]
+    MilestonesRequired,
+    
+    #[msg("Milestone schedule account is missing")]
+    MilestoneScheduleMissing,
+    
+    #[msg("Milestone is not unlocked yet")]
+    MilestoneLocked,
+    
+    #[msg("Milestone vote is not open")]
+    MilestoneVotingClosed,
+    
+    #[msg("Milestone vote has not ended")]
+    MilestoneVotingInProgress,
+    
+    #[msg("Already voted")]
+    AlreadyVoted,
+    
+    #[msg("Invalid governance configuration")]
+    InvalidGovernanceConfig,
+    
+    #[msg("Invalid proposal")]
+    InvalidProposal,
+    
+    #[msg("Proposal voting is not open")]
+    ProposalVotingClosed,
+    
+    #[msg("Proposal voting has not ended")]
+    ProposalVotingInProgress,
+    
+    #[msg("No voting power")]
+    NoVotingPower,
+    
+    #[msg("Investor position is not settled")]
+    PositionNotSettled,
+    
+    #[msg("Launch cannot be closed yet")]
+    LaunchNotClosable,
+    
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    
+    #[msg("Invalid withdrawal configuration")]
+    InvalidWithdrawalConfig,
+    
+    #[msg("Withdrawals are not open for this launch")]
+    WithdrawalNotAllowed,
+    
+    #[msg("Refund window has not closed")]
+    RefundWindowOpen,
+    
+    #[msg("Refunds have been swept to the refund holding account")]
+    RefundsSwept,
+    
+    #[msg("Invalid token extension configuration")]
+    InvalidTokenExtensions,
+    
+    #[msg("Token program does not match the launch mint")]
+    InvalidTokenProgram,
+    
+    #[msg("Launch does not sell from an existing mint")]
+    ExistingMintRequired,
+    
+    #[msg("Launch vault does not hold the sale allocation")]
+    LaunchNotFunded,
+    
+    #[msg("Creator token account is required")]
+    CreatorTokenAccountMissing,
+    
+    #[msg("Invalid mint authority policy")]
+    InvalidMintAuthorityPolicy,
+    
+    #[msg("Minting would exceed the launch total supply")]
+    SupplyCapExceeded,
+    
+    #[msg("Invalid unsold token policy")]
+    InvalidUnsoldTokenPolicy,
+    
+    #[msg("Liquidity token account is missing or does not match the launch policy")]
+    LiquidityAccountMissing,
+    
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Launch metadata is locked after approval")]
+    MetadataLocked,
+    
+    #[msg("Launch IDs can only be advanced")]
+    InvalidLaunchId,
+    
+    #[msg("Investor keeps a contribution ledger; pass its current page")]
+    ContributionLedgerMissing,
+    
+    #[msg("Contribution ledger page does not match the next entry")]
+    InvalidContributionLedger,
+    
+    #[msg("Position transfers are not allowed for this launch at this stage")]
+    PositionTransferNotAllowed,
+    
+    #[msg("Position has been refunded and cannot be transferred")]
+    PositionNotTransferable,
+    
+    #[msg("Position holds only allocation received after voting opened")]
+    PositionReceivedDuringVote,
+    
+    #[msg("Position cannot be transferred to the wallet that holds it")]
+    InvalidPositionRecipient,
+    
+    #[msg("Launch mints position NFTs; pass the position NFT accounts")]
+    PositionNftAccountsMissing,
+    
+    #[msg("Position NFT account does not match the investor position")]
+    InvalidPositionNft,
+    
+    #[msg("Signer does not hold the position NFT")]
+    PositionNftNotHeld,
+    
+    #[msg("Staking tiers are invalid")]
+    InvalidStakingTiers,
+    
+    #[msg("Staking has not been configured")]
+    StakingNotConfigured,
+    
+    #[msg("Stake mint cannot be changed once set")]
+    StakeMintLocked,
+    
+    #[msg("Stake lock period is invalid")]
+    InvalidStakeLock,
+    
+    #[msg("Stake is still locked")]
+    StakeLocked,
+    
+    #[msg("Contributions are already attributed to another referrer")]
+    ReferrerMismatch,
+    
+    #[msg("Position has referred contributions; pass the referrer account")]
+    ReferrerAccountMissing,
+    
+    #[msg("Stake has no guaranteed allocation left to reserve")]
+    NoAllocationToReserve,
+    
+    #[msg("Stake must stay locked until the sale ends")]
+    StakeLockTooShort,
+    
+    #[msg("Existing mint must not have a freeze authority")]
+    MintHasFreezeAuthority,
+    
+    #[msg("Existing mint has an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+    
+    #[msg("Reservations in this launch have closed")]
+    ReservationPeriodEnded,
+    
+    #[msg("Stake already backs a reservation in another launch")]
+    ReservationOutstanding,
+    
+    #[msg("Token mint does not match the launch")]
+    InvalidLaunchMint,
+}
@@ -0,0 +1,329 @@
+use anchor_lang::prelude::*;
+
+/// Emitted for each investor refunded by the refund crank
+#[event]
+pub struct RefundProcessed {
+    /// Launch the refund was paid from
+    pub launch_id: u64,
+    /// Investor receiving the refund
+    pub investor: Pubkey,
+    /// Amount refunded in lamports
+    pub amount: u64,
+    /// Account that submitted the crank transaction
+    pub processor: Pubkey,
+    /// Unix timestamp of the refund
+    pub timestamp: i64,
+}
+
+/// Emitted for each investor paid by the token distribution crank
+#[event]
+pub struct TokensDistributed {
+    /// Launch the tokens were distributed from
+    pub launch_id: u64,
+    /// Investor receiving the tokens
+    pub investor: Pubkey,
+    /// Amount of tokens transferred
+    pub amount: u64,
+    /// Account that submitted the crank transaction
+    pub distributor: Pubkey,
+    /// Unix timestamp of the distribution
+    pub timestamp: i64,
+}
+
+/// Emitted once when the platform configuration is initialized
+#[event]
+pub struct PlatformInitialized {
+    /// Platform admin
+    pub admin: Pubkey,
+    /// Platform treasury collecting fees
+    pub treasury: Pubkey,
+    /// Platform fee in basis points
+    pub platform_fee_percentage: u16,
+    /// Minimum launch duration in seconds
+    pub min_launch_duration: i64,
+    /// Maximum launch duration in seconds
+    pub max_launch_duration: i64,
+    /// Minimum soft cap in lamports
+    pub min_soft_cap: u64,
+    /// Unix timestamp of the initialization
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator submits a launch for approval
+#[event]
+pub struct LaunchCreated {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Launch creator
+    pub creator: Pubkey,
+    /// Mint of the token being sold
+    pub token_mint: Pubkey,
+    /// Token program owning the mint
+    pub token_program: Pubkey,
+    /// Whether the launch sells deposited supply of an existing mint
+    pub uses_existing_mint: bool,
+    /// Total token supply
+    pub total_supply: u64,
+    /// Lamports per whole token
+    pub presale_price: u64,
+    /// Soft cap in lamports
+    pub soft_cap: u64,
+    /// Hard cap in lamports
+    pub hard_cap: u64,
+    /// Presale start time
+    pub start_time: i64,
+    /// Presale end time
+    pub end_time: i64,
+    /// Listing fee paid to the platform treasury in lamports
+    pub listing_fee: u64,
+    /// Creator bond held by the launch in lamports
+    pub creator_bond: u64,
+    /// Unix timestamp of the creation
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator edits a launch that is still pending
+#[event]
+pub struct LaunchUpdated {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Launch creator
+    pub creator: Pubkey,
+    /// Lamports per whole token
+    pub presale_price: u64,
+    /// Soft cap in lamports
+    pub soft_cap: u64,
+    /// Hard cap in lamports
+    pub hard_cap: u64,
+    /// Presale start time
+    pub start_time: i64,
+    /// Presale end time
+    pub end_time: i64,
+    /// Whether a configured milestone schedule was discarded
+    pub milestones_reset: bool,
+    /// Unix timestamp of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin approves a pending launch
+#[event]
+pub struct LaunchApproved {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Approving admin
+    pub admin: Pubkey,
+    /// Unix timestamp of the approval
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin rejects a pending launch
+#[event]
+pub struct LaunchRejected {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Rejecting admin
+    pub admin: Pubkey,
+    /// Creator bond slashed to the platform treasury in lamports
+    pub bond_slashed: u64,
+    /// Unix timestamp of the rejection
+    pub timestamp: i64,
+}
+
+/// Emitted for each contribution to a launch
+#[event]
+pub struct ContributionMade {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Contributing investor
+    pub investor: Pubkey,
+    /// Contribution in lamports
+    pub amount: u64,
+    /// Tokens allocated for this contribution
+    pub token_allocation: u64,
+    /// Investor's total contribution in lamports
+    pub investor_total: u64,
+    /// Launch total raised in lamports after this contribution
+    pub total_raised: u64,
+    /// Referrer credited with the contribution, if any
+    pub referrer: Option<Pubkey>,
+    /// Unix timestamp of the contribution
+    pub timestamp: i64,
+}
+
+/// Emitted when a launch is finalized, whether it succeeded or failed
+#[event]
+pub struct LaunchFinalized {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Launch creator
+    pub creator: Pubkey,
+    /// Whether the soft cap was reached
+    pub successful: bool,
+    /// Total raised in lamports
+    pub total_raised: u64,
+    /// Platform fee in lamports, including the referral and insurance shares
+    pub platform_fee: u64,
+    /// Referral pool kept in the launch treasury in lamports
+    pub referral_pool: u64,
+    /// Insurance share sent to the insurance fund in lamports
+    pub insurance_amount: u64,
+    /// Creator proceeds paid out or escrowed behind milestones in lamports
+    pub creator_amount: u64,
+    /// Tokens sold to investors
+    pub tokens_sold: u64,
+    /// Tokens left unsold below the hard cap allocation
+    pub unsold_tokens: u64,
+    /// Creator bond returned on success or slashed on failure in lamports
+    pub bond_amount: u64,
+    /// Unix timestamp of the finalization
+    pub timestamp: i64,
+}
+
+/// Emitted when an investor claims vested tokens
+#[event]
+pub struct TokensClaimed {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Claiming investor
+    pub investor: Pubkey,
+    /// Tokens claimed by this transaction
+    pub amount: u64,
+    /// Investor's total claimed tokens
+    pub total_claimed: u64,
+    /// Unix timestamp of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted when an investor claims a refund
+#[event]
+pub struct RefundClaimed {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Refunded investor
+    pub investor: Pubkey,
+    /// Refund in lamports
+    pub amount: u64,
+    /// Whether the refund was paid from the refund holding account
+    pub from_refund_holding: bool,
+    /// Unix timestamp of the refund
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin emergency pauses a launch
+#[event]
+pub struct LaunchPaused {
+    /// Launch identifier
+    pub launch_id: u64,
+    /// Pausing admin
+    pub admin: Pubkey,
+    /// Unix timestamp of the pause
+    pub timestamp: i64,
+}
+
+/// Emitted with the resulting platform configuration after an update
+#[event]
+pub struct ConfigUpdated {
+    /// Admin that made the update
+    pub admin: Pubkey,
+    /// Platform fee in basis points
+    pub platform_fee_percentage: u16,
+    /// Minimum launch duration in seconds
+    pub min_launch_duration: i64,
+    /// Maximum launch duration in seconds
+    pub max_launch_duration: i64,
+    /// Minimum soft cap in lamports
+    pub min_soft_cap: u64,
+    /// Listing fee in lamports
+    pub listing_fee: u64,
+    /// Creator bond in lamports
+    pub creator_bond_amount: u64,
+    /// Referral share of the platform fee in basis points
+    pub referral_fee_share: u16,
+    /// Insurance share of the platform fee in basis points
+    pub insurance_fee_share: u16,
+    /// Hard cap above which milestones are required in lamports
+    pub milestone_threshold: u64,
+    /// Seconds after a launch ends before refunds can be swept
+    pub refund_window: i64,
+    /// Identifier of the most recent launch; the next launch gets the one after it
+    pub last_launch_id: u64,
+    /// Unix timestamp of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin withdraws fees from the platform treasury
+#[event]
+pub struct FeesCollected {
+    /// Collecting admin
+    pub admin: Pubkey,
+    /// Platform treasury the fees were taken from
+    pub platform_treasury: Pubkey,
+    /// Amount collected in lamports
+    pub amount: u64,
+    /// Unix timestamp of the collection
+    pub timestamp: i64,
+}
+
+/// Emitted when an investor moves all or part of a position to another wallet
+#[event]
+pub struct PositionTransferred {
+    /// Launch the position belongs to
+    pub launch_id: u64,
+    /// Wallet the position was moved from
+    pub from: Pubkey,
+    /// Wallet the position was moved to
+    pub to: Pubkey,
+    /// Token allocation moved
+    pub token_amount: u64,
+    /// Contribution moved with the allocation in lamports
+    pub contribution_amount: u64,
+    /// Claimed tokens moved with the allocation
+    pub claimed_amount: u64,
+    /// Unix timestamp of the transfer
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet stakes platform tokens
+#[event]
+pub struct TokensStaked {
+    /// Staking wallet
+    pub owner: Pubkey,
+    /// Tokens added to the stake
+    pub amount: u64,
+    /// Total tokens staked by the wallet
+    pub total_staked: u64,
+    /// Unix timestamp until which the stake is locked
+    pub locked_until: i64,
+    /// Unix timestamp of the stake
+    pub timestamp: i64,
+}
+
+/// Emitted when a staker reserves their guaranteed allocation in a launch
+#[event]
+pub struct AllocationReserved {
+    /// Launch ID
+    pub launch_id: u64,
+    /// Staking wallet
+    pub investor: Pubkey,
+    /// Lamports reserved by this call
+    pub amount: u64,
+    /// Lamports reserved in the launch and not yet contributed
+    pub total_reserved: u64,
+    /// Unix timestamp at which unused reservations in the launch lapse
+    pub expires_at: i64,
+    /// Unix timestamp of the reservation
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet withdraws staked platform tokens
+#[event]
+pub struct TokensUnstaked {
+    /// Staking wallet
+    pub owner: Pubkey,
+    /// Tokens withdrawn from the stake
+    pub amount: u64,
+    /// Tokens still staked by the wallet
+    pub total_staked: u64,
+    /// Unix timestamp of the withdrawal
+    pub timestamp: i64,
+}
@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::state::{LaunchConfig, PlatformConfig, LaunchStatus};
+use crate::state::{
+    LaunchConfig, PlatformConfig, PlatformConfigUpdate, PlatformStats, LaunchStatus, RefundHolding,
+    LaunchRegistry, RegistryBucket,
+};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::{ConfigUpdated, FeesCollected, LaunchApproved, LaunchPaused, LaunchRejected};
+use crate::utils::transfer_from_treasury;
 
 // Approve Launch
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct ApproveLaunch<'info> {
@@ -21,7 +27,36 @@ pub struct ApproveLaunch<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Pending.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub pending_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Active.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub active_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(mut)]
     pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 pub fn approve_launch(ctx: Context<ApproveLaunch>) -> Result<()> {
@@ -38,19 +73,37 @@ pub fn approve_launch(ctx: Context<ApproveLaunch>) -> Result<()> {
         return Err(LaunchpadError::InvalidPresaleTime.into());
     }
 
+    // Large raises must escrow creator proceeds behind milestones
+    if ctx.accounts.platform_config.requires_milestones(launch_config.hard_cap)
+        && !launch_config.uses_milestones
+    {
+        return Err(LaunchpadError::MilestonesRequired.into());
+    }
+
+    // Existing-mint launches must hold inventory for a sale up to the hard cap
+    if !launch_config.is_funded()? {
+        return Err(LaunchpadError::LaunchNotFunded.into());
+    }
+
     // Approve the launch
     launch_config.status = LaunchStatus::Active;
 
-    msg!(
-        "Launch {} approved by admin {}",
-        launch_config.launch_id,
-        ctx.accounts.admin.key()
-    );
+    // Move the launch from the pending to the active bucket
+    let launch_id = launch_config.launch_id;
+    ctx.accounts.pending_registry.unlist(RegistryBucket::Pending, launch_id, ctx.bumps.pending_registry);
+    ctx.accounts.active_registry.list(RegistryBucket::Active, launch_id, ctx.bumps.active_registry);
+
+    emit_cpi!(LaunchApproved {
+        launch_id: launch_config.launch_id,
+        admin: ctx.accounts.admin.key(),
+        timestamp: current_time,
+    });
 
     Ok(())
 }
 
 // Reject Launch
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct RejectLaunch<'info> {
@@ -68,10 +121,54 @@ pub struct RejectLaunch<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Platform treasury receiving the slashed creator bond
+    #[account(
+        mut,
+        constraint = platform_treasury.key() == platform_config.treasury @ LaunchpadError::Unauthorized
+    )]
+    pub platform_treasury: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Pending.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub pending_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Ended.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ended_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(mut)]
     pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 pub fn reject_launch(ctx: Context<RejectLaunch>) -> Result<()> {
+    let launch_account = ctx.accounts.launch_config.to_account_info();
     let launch_config = &mut ctx.accounts.launch_config;
     
     // Check if launch can be rejected
@@ -79,19 +176,131 @@ pub fn reject_launch(ctx: Context<RejectLaunch>) -> Result<()> {
         return Err(LaunchpadError::CannotCancelLaunch.into());
     }
 
+    // Slash the creator bond to the platform treasury
+    let bond_slashed = launch_config.release_creator_bond(
+        &launch_account,
+        &ctx.accounts.platform_treasury,
+    )?;
+    ctx.accounts.platform_stats.record_rejection(bond_slashed)?;
+
     // Reject the launch
     launch_config.status = LaunchStatus::Cancelled;
 
+    // Move the launch from the pending to the ended bucket
+    let launch_id = launch_config.launch_id;
+    ctx.accounts.pending_registry.unlist(RegistryBucket::Pending, launch_id, ctx.bumps.pending_registry);
+    ctx.accounts.ended_registry.list(RegistryBucket::Ended, launch_id, ctx.bumps.ended_registry);
+
+    emit_cpi!(LaunchRejected {
+        launch_id: launch_config.launch_id,
+        admin: ctx.accounts.admin.key(),
+        bond_slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Cancel Launch
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct CancelLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Platform treasury receiving the slashed creator bond
+    #[account(
+        mut,
+        constraint = platform_treasury.key() == platform_config.treasury @ LaunchpadError::Unauthorized
+    )]
+    pub platform_treasury: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Active.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub active_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Ended.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ended_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_launch(ctx: Context<CancelLaunch>, _launch_id: u64) -> Result<()> {
+    let launch_account = ctx.accounts.launch_config.to_account_info();
+    let launch_config = &mut ctx.accounts.launch_config;
+    
+    // Only approved launches that have not been finalized can be cancelled
+    if launch_config.status != LaunchStatus::Active && launch_config.status != LaunchStatus::Paused {
+        return Err(LaunchpadError::CannotCancelLaunch.into());
+    }
+
+    // Slash the creator bond to the platform treasury
+    let bond_slashed = launch_config.release_creator_bond(
+        &launch_account,
+        &ctx.accounts.platform_treasury,
+    )?;
+    ctx.accounts.platform_stats.record_cancellation(bond_slashed)?;
+
+    // Cancel the launch, contributions become refundable
+    launch_config.status = LaunchStatus::Cancelled;
+
+    // Move the launch from the active to the ended bucket
+    let launch_id = launch_config.launch_id;
+    ctx.accounts.active_registry.unlist(RegistryBucket::Active, launch_id, ctx.bumps.active_registry);
+    ctx.accounts.ended_registry.list(RegistryBucket::Ended, launch_id, ctx.bumps.ended_registry);
+
     msg!(
-        "Launch {} rejected by admin {}",
+        "Launch {} cancelled by admin {}, bond slashed: {} lamports",
         launch_config.launch_id,
-        ctx.accounts.admin.key()
+        ctx.accounts.admin.key(),
+        bond_slashed
     );
 
     Ok(())
 }
 
 // Emergency Pause
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct EmergencyPause<'info> {
@@ -123,16 +332,17 @@ pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
     // Pause the launch
     launch_config.status = LaunchStatus::Paused;
 
-    msg!(
-        "Launch {} emergency paused by admin {}",
-        launch_config.launch_id,
-        ctx.accounts.admin.key()
-    );
+    emit_cpi!(LaunchPaused {
+        launch_id: launch_config.launch_id,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
 
 // Update Platform Config
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePlatformConfig<'info> {
     #[account(
@@ -148,15 +358,12 @@ pub struct UpdatePlatformConfig<'info> {
 
 pub fn update_platform_config(
     ctx: Context<UpdatePlatformConfig>,
-    platform_fee_percentage: Option<u16>,
-    min_launch_duration: Option<i64>,
-    max_launch_duration: Option<i64>,
-    min_soft_cap: Option<u64>,
+    update: PlatformConfigUpdate,
 ) -> Result<()> {
     let platform_config = &mut ctx.accounts.platform_config;
 
     // Update platform fee if provided
-    if let Some(fee) = platform_fee_percentage {
+    if let Some(fee) = update.platform_fee_percentage {
         if fee > 5000 { // Max 50%
             return Err(LaunchpadError::InvalidPlatformFee.into());
         }
@@ -164,14 +371,14 @@ pub fn update_platform_config(
     }
 
     // Update launch duration limits if provided
-    if let Some(min_duration) = min_launch_duration {
+    if let Some(min_duration) = update.min_launch_duration {
         if min_duration <= 0 {
             return Err(LaunchpadError::InvalidLaunchDuration.into());
         }
         platform_config.min_launch_duration = min_duration;
     }
 
-    if let Some(max_duration) = max_launch_duration {
+    if let Some(max_duration) = update.max_launch_duration {
         if max_duration <= 0 || max_duration <= platform_config.min_launch_duration {
             return Err(LaunchpadError::InvalidLaunchDuration.into());
         }
@@ -179,22 +386,172 @@ pub fn update_platform_config(
     }
 
     // Update minimum soft cap if provided
-    if let Some(soft_cap) = min_soft_cap {
+    if let Some(soft_cap) = update.min_soft_cap {
         if soft_cap == 0 {
             return Err(LaunchpadError::InvalidSoftCap.into());
         }
         platform_config.min_soft_cap = soft_cap;
     }
 
+    // Update listing fee and creator bond if provided
+    if let Some(fee) = update.listing_fee {
+        platform_config.listing_fee = fee;
+    }
+
+    if let Some(bond) = update.creator_bond_amount {
+        platform_config.creator_bond_amount = bond;
+    }
+
+    // Update referral and insurance fee shares if provided
+    if let Some(share) = update.referral_fee_share {
+        if share > BASIS_POINTS_MAX {
+            return Err(LaunchpadError::InvalidReferralShare.into());
+        }
+        platform_config.referral_fee_share = share;
+    }
+
+    if let Some(share) = update.insurance_fee_share {
+        if share > BASIS_POINTS_MAX {
+            return Err(LaunchpadError::InvalidInsuranceShare.into());
+        }
+        platform_config.insurance_fee_share = share;
+    }
+
+    // Both shares are carved out of the same platform fee
+    if platform_config.referral_fee_share as u32 + platform_config.insurance_fee_share as u32
+        > BASIS_POINTS_MAX as u32
+    {
+        return Err(LaunchpadError::InvalidInsuranceShare.into());
+    }
+
+    // Update milestone escrow threshold if provided (0 disables the requirement)
+    if let Some(threshold) = update.milestone_threshold {
+        platform_config.milestone_threshold = threshold;
+    }
+
+    // Update refund window if provided (0 disables sweeping)
+    if let Some(window) = update.refund_window {
+        if window < 0 {
+            return Err(LaunchpadError::InvalidLaunchDuration.into());
+        }
+        platform_config.refund_window = window;
+    }
+
+    // Skip launch IDs if provided, e.g. past IDs chosen by clients before allocation
+    if let Some(launch_id) = update.last_launch_id {
+        if launch_id < platform_config.last_launch_id {
+            return Err(LaunchpadError::InvalidLaunchId.into());
+        }
+        platform_config.last_launch_id = launch_id;
+    }
+
+    emit_cpi!(ConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        platform_fee_percentage: platform_config.platform_fee_percentage,
+        min_launch_duration: platform_config.min_launch_duration,
+        max_launch_duration: platform_config.max_launch_duration,
+        min_soft_cap: platform_config.min_soft_cap,
+        listing_fee: platform_config.listing_fee,
+        creator_bond_amount: platform_config.creator_bond_amount,
+        referral_fee_share: platform_config.referral_fee_share,
+        insurance_fee_share: platform_config.insurance_fee_share,
+        milestone_threshold: platform_config.milestone_threshold,
+        refund_window: platform_config.refund_window,
+        last_launch_id: platform_config.last_launch_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Sweep Refunds
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct SweepRefunds<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Treasury account holding unclaimed refunds
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = RefundHolding::LEN,
+        seeds = [REFUND_HOLDING_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub refund_holding: Account<'info, RefundHolding>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sweep_refunds(ctx: Context<SweepRefunds>, launch_id: u64) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Only failed or cancelled launches hold refunds
+    if launch_config.status != LaunchStatus::Failed && launch_config.status != LaunchStatus::Cancelled {
+        return Err(LaunchpadError::RefundNotAvailable.into());
+    }
+
+    if !ctx.accounts.platform_config.is_refund_window_closed(launch_config.end_time, current_time) {
+        return Err(LaunchpadError::RefundWindowOpen.into());
+    }
+
+    // Move the remaining treasury balance into the holding account
+    let swept_amount = ctx.accounts.treasury_account.lamports();
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.refund_holding.to_account_info(),
+        launch_id,
+        ctx.bumps.treasury_account,
+        swept_amount,
+    )?;
+
+    let refund_holding = &mut ctx.accounts.refund_holding;
+    refund_holding.launch_id = launch_id;
+    refund_holding.swept_amount = swept_amount;
+    refund_holding.claimed_amount = 0;
+    refund_holding.swept_at = current_time;
+    refund_holding.presale_price = launch_config.presale_price;
+    refund_holding.bump = ctx.bumps.refund_holding;
+
+    // Outstanding investor accounts now claim against the holding account
+    launch_config.refunds_swept = true;
+
     msg!(
-        "Platform configuration updated by admin {}",
-        ctx.accounts.admin.key()
+        "Unclaimed refunds swept for launch {} by admin {}: {} lamports",
+        launch_id,
+        ctx.accounts.admin.key(),
+        swept_amount
     );
 
     Ok(())
 }
 
 // Collect Fees
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CollectFees<'info> {
     #[account(
@@ -204,6 +561,13 @@ pub struct CollectFees<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     /// CHECK: Platform treasury account
     #[account(
         mut,
@@ -227,12 +591,14 @@ pub fn collect_fees(ctx: Context<CollectFees>, amount: u64) -> Result<()> {
     // Transfer fees from treasury to admin
     **ctx.accounts.platform_treasury.try_borrow_mut_lamports()? -= amount;
     **ctx.accounts.admin.try_borrow_mut_lamports()? += amount;
+    ctx.accounts.platform_stats.record_fees_withdrawn(amount)?;
 
-    msg!(
-        "Fees collected: {} lamports by admin {}",
+    emit_cpi!(FeesCollected {
+        admin: ctx.accounts.admin.key(),
+        platform_treasury: ctx.accounts.platform_treasury.key(),
         amount,
-        ctx.accounts.admin.key()
-    );
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
\ No newline at end of file
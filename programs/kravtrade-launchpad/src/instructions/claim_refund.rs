@@ -1,20 +1,25 @@
 use anchor_lang::prelude::*;
-use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus};
+use anchor_spl::token::{self, Token};
+use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus, PlatformStats, RefundHolding, LedgerEntryKind};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::RefundClaimed;
+use crate::utils::{authorize_position_holder, burn_position_nft, record_ledger_entry, transfer_from_treasury};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct ClaimRefund<'info> {
+    /// Omitted once the launch is closed; refunds are then paid from the refund holding account
     #[account(
         seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
         bump = launch_config.bump
     )]
-    pub launch_config: Account<'info, LaunchConfig>,
+    pub launch_config: Option<Account<'info, LaunchConfig>>,
     
     #[account(
         mut,
-        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
         bump = investor_account.bump
     )]
     pub investor_account: Account<'info, InvestorAccount>,
@@ -27,6 +32,36 @@ pub struct ClaimRefund<'info> {
     )]
     pub treasury_account: AccountInfo<'info>,
     
+    /// Required once unclaimed refunds have been swept
+    #[account(
+        mut,
+        seeds = [REFUND_HOLDING_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = refund_holding.bump
+    )]
+    pub refund_holding: Option<Account<'info, RefundHolding>>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+    
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    #[account(mut)]
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+    
+    /// Position NFT mint, required to burn the NFT when the position is settled
+    #[account(mut)]
+    pub position_mint: Option<Account<'info, token::Mint>>,
+    
+    pub position_token_program: Option<Program<'info, Token>>,
+    
     #[account(mut)]
     pub investor: Signer<'info>,
     
@@ -34,34 +69,96 @@ pub struct ClaimRefund<'info> {
 }
 
 pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
-    let launch_config = &ctx.accounts.launch_config;
+    let launch_config = ctx.accounts.launch_config.as_ref();
     let investor_account = &mut ctx.accounts.investor_account;
 
-    // Validate refund eligibility
-    validate_refund_eligibility(launch_config, investor_account)?;
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
 
-    let refund_amount = investor_account.contribution_amount;
+    // Validate refund eligibility. A refund holding account only exists for failed or
+    // cancelled launches, so it stands in for a closed launch.
+    let (refunds_swept, presale_price) = match (launch_config, ctx.accounts.refund_holding.as_ref()) {
+        (Some(launch_config), _) => {
+            validate_refund_eligibility(launch_config, investor_account)?;
+            (launch_config.refunds_swept, launch_config.presale_price)
+        }
+        (None, Some(refund_holding)) => {
+            validate_investor_eligibility(investor_account)?;
+            (true, refund_holding.presale_price)
+        }
+        (None, None) => return Err(LaunchpadError::RefundsSwept.into()),
+    };
 
-    // Transfer SOL from treasury back to investor
-    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
-    let treasury_seeds = &[
-        TREASURY_SEED,
-        launch_id_bytes.as_ref(),
-        &[ctx.bumps.treasury_account],
-    ];
+    let refund_amount = investor_account.contribution_amount;
 
-    **ctx.accounts.treasury_account.try_borrow_mut_lamports()? -= refund_amount;
-    **ctx.accounts.investor.try_borrow_mut_lamports()? += refund_amount;
+    if refunds_swept {
+        // Late claims are paid from the refund holding account
+        let refund_holding = ctx.accounts.refund_holding
+            .as_mut()
+            .ok_or(LaunchpadError::RefundsSwept)?;
+        let holding_account = refund_holding.to_account_info();
+        refund_holding.pay_refund(
+            &holding_account,
+            &ctx.accounts.investor.to_account_info(),
+            refund_amount,
+        )?;
+    } else {
+        // Transfer SOL from treasury back to investor
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            &ctx.accounts.investor.to_account_info(),
+            investor_account.launch_id,
+            ctx.bumps.treasury_account,
+            refund_amount,
+        )?;
+    }
 
     // Mark investor as refunded
     investor_account.mark_refunded();
-
-    msg!(
-        "Refund processed: {} lamports to {} for failed launch {}",
+    ctx.accounts.platform_stats.record_refund(refund_amount)?;
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::Refund,
         refund_amount,
-        ctx.accounts.investor.key(),
-        launch_config.launch_id
-    );
+        presale_price,
+    )?;
+
+    // The position NFT is burned with the refund
+    if investor_account.has_position_nft() {
+        let (Some(position_token_account), Some(position_mint), Some(position_token_program)) = (
+            ctx.accounts.position_token_account.as_ref(),
+            ctx.accounts.position_mint.as_ref(),
+            ctx.accounts.position_token_program.as_ref(),
+        ) else {
+            return Err(LaunchpadError::PositionNftAccountsMissing.into());
+        };
+        if position_mint.key() != investor_account.position_mint {
+            return Err(LaunchpadError::InvalidPositionNft.into());
+        }
+        burn_position_nft(
+            &position_token_program.to_account_info(),
+            &position_mint.to_account_info(),
+            &position_token_account.to_account_info(),
+            &ctx.accounts.investor.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(RefundClaimed {
+        launch_id: investor_account.launch_id,
+        investor: investor_account.investor,
+        amount: refund_amount,
+        from_refund_holding: refunds_swept,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -75,6 +172,10 @@ fn validate_refund_eligibility(
         return Err(LaunchpadError::RefundNotAvailable.into());
     }
 
+    validate_investor_eligibility(investor_account)
+}
+
+fn validate_investor_eligibility(investor_account: &InvestorAccount) -> Result<()> {
     // Check if investor is eligible for refund
     if !investor_account.is_eligible_for_refund() {
         return Err(LaunchpadError::AlreadyRefunded.into());
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus};
+use anchor_spl::token::{self, Token};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus, PlatformStats, LedgerEntryKind};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::TokensClaimed;
+use crate::utils::{authorize_position_holder, burn_position_nft, record_ledger_entry, release_tokens};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct ClaimTokens<'info> {
@@ -15,27 +19,60 @@ pub struct ClaimTokens<'info> {
     
     #[account(
         mut,
-        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
         bump = investor_account.bump
     )]
     pub investor_account: Account<'info, InvestorAccount>,
     
+    #[account(mut, address = launch_config.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
     #[account(
         mut,
-        associated_token::mint = launch_config.token_mint,
+        associated_token::mint = token_mint,
         associated_token::authority = launch_config,
+        associated_token::token_program = token_program,
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
-        associated_token::mint = launch_config.token_mint,
+        associated_token::mint = token_mint,
         associated_token::authority = investor,
+        associated_token::token_program = token_program,
+    )]
+    pub investor_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
     )]
-    pub investor_token_account: Account<'info, TokenAccount>,
+    pub platform_stats: Account<'info, PlatformStats>,
     
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+    
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    #[account(mut)]
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+    
+    /// Position NFT mint, required to burn the NFT when the position is settled
+    #[account(mut)]
+    pub position_mint: Option<Account<'info, token::Mint>>,
+    
+    pub position_token_program: Option<Program<'info, Token>>,
+    
+    #[account(mut)]
     pub investor: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    
+    #[account(
+        constraint = token_program.key() == launch_config.token_program @ LaunchpadError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
@@ -43,6 +80,13 @@ pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
     let investor_account = &mut ctx.accounts.investor_account;
     let current_time = Clock::get()?.unix_timestamp;
 
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
     // Validate claim eligibility
     validate_claim_eligibility(launch_config, investor_account)?;
 
@@ -58,36 +102,56 @@ pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
     }
 
     // Transfer tokens from vault to investor
-    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
-    let seeds = &[
-        LAUNCH_SEED,
-        launch_id_bytes.as_ref(),
-        &[launch_config.bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
-
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.token_vault.to_account_info(),
-                to: ctx.accounts.investor_token_account.to_account_info(),
-                authority: ctx.accounts.launch_config.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    release_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.token_vault.to_account_info(),
+        &ctx.accounts.investor_token_account.to_account_info(),
+        launch_config,
         claimable_amount,
     )?;
 
     // Update investor account
     investor_account.update_claimed_amount(claimable_amount, current_time)?;
-
-    msg!(
-        "Tokens claimed: {} tokens by {} for launch {}",
+    ctx.accounts.platform_stats.record_tokens_claimed(claimable_amount)?;
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::Claim,
         claimable_amount,
-        ctx.accounts.investor.key(),
-        launch_config.launch_id
-    );
+        launch_config.presale_price,
+    )?;
+
+    // The position NFT is burned once nothing is left to claim
+    if investor_account.has_position_nft() && investor_account.claimed_amount >= investor_account.token_allocation {
+        let (Some(position_token_account), Some(position_mint), Some(position_token_program)) = (
+            ctx.accounts.position_token_account.as_ref(),
+            ctx.accounts.position_mint.as_ref(),
+            ctx.accounts.position_token_program.as_ref(),
+        ) else {
+            return Err(LaunchpadError::PositionNftAccountsMissing.into());
+        };
+        if position_mint.key() != investor_account.position_mint {
+            return Err(LaunchpadError::InvalidPositionNft.into());
+        }
+        burn_position_nft(
+            &position_token_program.to_account_info(),
+            &position_mint.to_account_info(),
+            &position_token_account.to_account_info(),
+            &ctx.accounts.investor.to_account_info(),
+        )?;
+    }
+
+    emit_cpi!(TokensClaimed {
+        launch_id: launch_config.launch_id,
+        investor: investor_account.investor,
+        amount: claimable_amount,
+        total_claimed: investor_account.claimed_amount,
+        timestamp: current_time,
+    });
 
     Ok(())
 }
@@ -0,0 +1,306 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::state::{
+    LaunchConfig, LaunchMetadataAccount, InvestorAccount, MilestoneSchedule, LaunchRegistry,
+    RegistryBucket, LaunchStatus, PlatformConfig, PlatformStats,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::transfer_from_treasury;
+
+// Close Investor Account
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct CloseInvestorAccount<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        close = investor,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// Required when the launch escrows proceeds behind milestones
+    #[account(
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+
+    /// CHECK: Insurance claim PDA of the launch, only checked for existence
+    #[account(
+        seeds = [INSURANCE_CLAIM_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub insurance_claim: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub investor: Signer<'info>,
+}
+
+pub fn close_investor_account(ctx: Context<CloseInvestorAccount>, launch_id: u64) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+
+    let milestone_refunds_enabled = if launch_config.uses_milestones {
+        ctx.accounts.milestone_schedule
+            .as_ref()
+            .ok_or(LaunchpadError::MilestoneScheduleMissing)?
+            .refunds_enabled
+    } else {
+        false
+    };
+    let insurance_flagged = !ctx.accounts.insurance_claim.data_is_empty();
+
+    // Only fully claimed or refunded positions can be closed
+    if !ctx.accounts.investor_account.is_settled(
+        &launch_config.status,
+        milestone_refunds_enabled,
+        insurance_flagged,
+    ) {
+        return Err(LaunchpadError::PositionNotSettled.into());
+    }
+
+    launch_config.open_investor_accounts = launch_config.open_investor_accounts.saturating_sub(1);
+
+    msg!(
+        "Investor account closed for {} on launch {}, {} remaining",
+        ctx.accounts.investor.key(),
+        launch_id,
+        launch_config.open_investor_accounts
+    );
+
+    Ok(())
+}
+
+// Close Launch
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct CloseLaunch<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [LAUNCH_METADATA_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_metadata.bump
+    )]
+    pub launch_metadata: Account<'info, LaunchMetadataAccount>,
+
+    #[account(address = launch_config.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = launch_config,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Treasury account holding remaining lamports
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+
+    /// Required when the launch escrows proceeds behind milestones
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Ended.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ended_registry: Account<'info, LaunchRegistry>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+
+    /// CHECK: Platform treasury receiving referral rewards left unclaimed at sunset
+    #[account(
+        mut,
+        constraint = platform_treasury.key() == platform_config.treasury @ LaunchpadError::Unauthorized
+    )]
+    pub platform_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        constraint = token_program.key() == launch_config.token_program @ LaunchpadError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_launch(ctx: Context<CloseLaunch>, launch_id: u64) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if !launch_config.is_concluded() {
+        return Err(LaunchpadError::LaunchNotClosable.into());
+    }
+
+    // Every investor account must be closed unless the sunset period after the sale,
+    // or after vesting for a successful launch, has passed
+    let sunset_reached = current_time >= launch_config.sunset_time()?;
+    if launch_config.open_investor_accounts > 0 && !sunset_reached {
+        return Err(LaunchpadError::LaunchNotClosable.into());
+    }
+
+    // Unreleased milestone escrow must not be swept to the creator. Once it is refundable
+    // it belongs to investors, who cannot close their accounts before claiming it.
+    if launch_config.uses_milestones {
+        let milestone_schedule = ctx.accounts.milestone_schedule
+            .as_ref()
+            .ok_or(LaunchpadError::MilestoneScheduleMissing)?;
+        if milestone_schedule.unreleased_amount() > 0 && !milestone_schedule.refunds_enabled {
+            return Err(LaunchpadError::LaunchNotClosable.into());
+        }
+        if milestone_schedule.refunds_enabled && launch_config.open_investor_accounts > 0 {
+            return Err(LaunchpadError::LaunchNotClosable.into());
+        }
+    }
+
+    // Unclaimed referral rewards are held in the treasury until the sunset, then forfeited
+    // to the platform
+    let referral_pool = launch_config.referral_pool;
+    if referral_pool > 0 && !sunset_reached {
+        return Err(LaunchpadError::LaunchNotClosable.into());
+    }
+
+    // Unclaimed refunds of a failed or cancelled launch must be moved to the refund
+    // holding account before the treasury is swept
+    let refunds_outstanding = matches!(launch_config.status, LaunchStatus::Failed | LaunchStatus::Cancelled)
+        && launch_config.open_investor_accounts > 0
+        && !launch_config.refunds_swept;
+    if refunds_outstanding {
+        return Err(LaunchpadError::LaunchNotClosable.into());
+    }
+
+    let launch_id_bytes = launch_id.to_le_bytes();
+    let seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Return unclaimed tokens to the creator, then close the vault
+    let remaining_tokens = ctx.accounts.token_vault.amount;
+    if remaining_tokens > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    authority: ctx.accounts.launch_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining_tokens,
+            launch_config.decimals,
+        )?;
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.token_vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.launch_config.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    if referral_pool > 0 {
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            &ctx.accounts.platform_treasury,
+            launch_id,
+            ctx.bumps.treasury_account,
+            referral_pool,
+        )?;
+        ctx.accounts.platform_stats.record_fees(referral_pool)?;
+        ctx.accounts.launch_config.referral_pool = 0;
+    }
+
+    // Sweep the treasury to the creator
+    let treasury_balance = ctx.accounts.treasury_account.lamports();
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.creator.to_account_info(),
+        launch_id,
+        ctx.bumps.treasury_account,
+        treasury_balance,
+    )?;
+
+    if let Some(milestone_schedule) = &ctx.accounts.milestone_schedule {
+        milestone_schedule.close(ctx.accounts.creator.to_account_info())?;
+    }
+
+    // The launch no longer exists, but stays on the creator's profile
+    ctx.accounts.ended_registry.unlist(RegistryBucket::Ended, launch_id, ctx.bumps.ended_registry);
+
+    msg!(
+        "Launch {} closed by {}: {} tokens and {} treasury lamports returned, {} investor accounts left open",
+        launch_id,
+        ctx.accounts.creator.key(),
+        remaining_tokens,
+        treasury_balance,
+        ctx.accounts.launch_config.open_investor_accounts
+    );
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{LaunchConfig, InvestorAccount, PlatformConfig, LaunchStatus};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use crate::state::{LaunchConfig, InvestorAccount, PlatformConfig, PlatformStats, LaunchStatus, Referrer, LedgerEntryKind, StakeAccount};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::ContributionMade;
+use crate::utils::{mint_position_nft, record_ledger_entry};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct Contribute<'info> {
@@ -29,6 +34,13 @@ pub struct Contribute<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     /// CHECK: Treasury account for holding contributions
     #[account(
         mut,
@@ -37,6 +49,53 @@ pub struct Contribute<'info> {
     )]
     pub treasury_account: AccountInfo<'info>,
     
+    /// Optional referrer registered for this launch
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, launch_config.launch_id.to_le_bytes().as_ref(), referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump,
+        constraint = referrer_account.referrer != investor.key() @ LaunchpadError::SelfReferral
+    )]
+    pub referrer_account: Option<Account<'info, Referrer>>,
+    
+    /// Optional stake of the investor, raising the max contribution to its staking tier
+    /// and holding its reserved allocation
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, investor.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Option<Account<'info, StakeAccount>>,
+    
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Position NFT mint PDA, created in the handler on the first contribution to a
+    /// launch with position NFTs
+    #[account(mut)]
+    pub position_mint: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Investor's associated token account for the position NFT, created in the handler
+    #[account(mut)]
+    pub position_token_account: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Metadata PDA of the position NFT, checked by the token metadata program
+    #[account(mut)]
+    pub position_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Master edition PDA of the position NFT, checked by the token metadata program
+    #[account(mut)]
+    pub position_master_edition: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    
+    pub position_token_program: Option<Program<'info, Token>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
     #[account(mut)]
     pub investor: Signer<'info>,
     
@@ -57,29 +116,81 @@ pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
     // Check launch status and timing
     validate_contribution_eligibility(launch_config, current_time)?;
 
-    // Validate contribution amount
-    launch_config.validate_contribution(amount)?;
+    // Validate the investor's total against their staking tier, if any. Their reserved
+    // allocation is used first and does not compete for the rest of the hard cap.
+    let max_contribution = ctx.accounts.stake_account.as_ref()
+        .and_then(|stake_account| stake_account.tier(&platform_config.staking_tiers, current_time))
+        .map_or(launch_config.max_contribution, |tier| tier.max_contribution(launch_config.max_contribution));
+    let investor_total = investor_account.contribution_amount
+        .checked_add(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    let reserved = ctx.accounts.stake_account
+        .as_ref()
+        .map_or(0, |stake_account| stake_account.reservation(launch_config.launch_id, current_time))
+        .min(amount);
+    launch_config.validate_contribution(amount, investor_total, max_contribution, reserved, current_time)?;
 
     // Check if this is a new investor account
     let is_new_investor = investor_account.investor == Pubkey::default();
 
     // Initialize investor account if needed
     if is_new_investor {
-        investor_account.investor = ctx.accounts.investor.key();
-        investor_account.launch_id = launch_config.launch_id;
-        investor_account.contribution_amount = 0;
-        investor_account.token_allocation = 0;
-        investor_account.claimed_amount = 0;
-        investor_account.last_claim_time = 0;
-        investor_account.is_refunded = false;
-        investor_account.bump = ctx.bumps.investor_account;
+        investor_account.initialize(
+            ctx.accounts.investor.key(),
+            launch_config.launch_id,
+            ctx.bumps.investor_account,
+        );
         
-        // Increment contributor count for new investors
+        launch_config.open_investor_accounts = launch_config.open_investor_accounts
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        ctx.accounts.platform_stats.record_contributor()?;
+    }
+
+    // Investors count as contributors while they hold a contribution, so one who
+    // withdrew everything is counted again on contributing anew
+    if investor_account.contribution_amount == 0 {
         launch_config.contributor_count = launch_config.contributor_count
             .checked_add(1)
             .ok_or(LaunchpadError::ArithmeticOverflow)?;
     }
 
+    // The first contribution to a launch with position NFTs mints the investor's NFT
+    if launch_config.position_nfts && !investor_account.has_position_nft() {
+        let (
+            Some(position_mint),
+            Some(position_token_account),
+            Some(position_metadata),
+            Some(position_master_edition),
+            Some(token_metadata_program),
+            Some(position_token_program),
+            Some(associated_token_program),
+        ) = (
+            ctx.accounts.position_mint.as_ref(),
+            ctx.accounts.position_token_account.as_ref(),
+            ctx.accounts.position_metadata.as_ref(),
+            ctx.accounts.position_master_edition.as_ref(),
+            ctx.accounts.token_metadata_program.as_ref(),
+            ctx.accounts.position_token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        ) else {
+            return Err(LaunchpadError::PositionNftAccountsMissing.into());
+        };
+
+        investor_account.position_mint = mint_position_nft(
+            launch_config,
+            &ctx.accounts.investor.to_account_info(),
+            &position_mint.to_account_info(),
+            &position_token_account.to_account_info(),
+            &position_metadata.to_account_info(),
+            &position_master_edition.to_account_info(),
+            &token_metadata_program.to_account_info(),
+            &position_token_program.to_account_info(),
+            &associated_token_program.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+    }
+
     // Calculate token allocation for this contribution
     let token_allocation = launch_config.calculate_token_allocation(amount)?;
 
@@ -104,28 +215,61 @@ pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         .checked_add(token_allocation)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
 
+    // Record the purchase in the investor's ledger, if kept
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::Contribution,
+        amount,
+        launch_config.presale_price,
+    )?;
+
     // Update launch statistics
     launch_config.total_raised = launch_config.total_raised
         .checked_add(amount)
         .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if let Some(stake_account) = ctx.accounts.stake_account.as_mut() {
+        stake_account.reserved_allocation -= reserved;
+    }
+    launch_config.reserved_allocation = launch_config.reserved_allocation
+        .checked_sub(reserved)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    // Attribute the contribution to the referrer, if any. A position has a single
+    // referrer, so withdrawals can reverse the attribution.
+    if let Some(referrer_account) = ctx.accounts.referrer_account.as_mut() {
+        if investor_account.referrer == Pubkey::default() {
+            investor_account.referrer = referrer_account.referrer;
+        } else if investor_account.referrer != referrer_account.referrer {
+            return Err(LaunchpadError::ReferrerMismatch.into());
+        }
+        investor_account.referred_amount = investor_account.referred_amount
+            .checked_add(amount)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        referrer_account.record_referral(amount)?;
+        launch_config.referred_volume = launch_config.referred_volume
+            .checked_add(amount)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
 
     // Check if hard cap is reached and update status
     if launch_config.has_reached_hard_cap() {
         launch_config.status = LaunchStatus::Successful;
     }
 
-    msg!(
-        "Contribution received: {} lamports from {} for launch {}",
+    emit_cpi!(ContributionMade {
+        launch_id: launch_config.launch_id,
+        investor: investor_account.investor,
         amount,
-        ctx.accounts.investor.key(),
-        launch_config.launch_id
-    );
-
-    msg!(
-        "Token allocation: {} tokens, Total raised: {} lamports",
         token_allocation,
-        launch_config.total_raised
-    );
+        investor_total: investor_account.contribution_amount,
+        total_raised: launch_config.total_raised,
+        referrer: ctx.accounts.referrer_account.as_ref().map(|referrer| referrer.referrer),
+        timestamp: current_time,
+    });
 
     Ok(())
 }
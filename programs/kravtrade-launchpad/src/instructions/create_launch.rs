@@ -1,48 +1,127 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
-use anchor_spl::associated_token::AssociatedToken;
-use crate::state::{LaunchConfig, PlatformConfig, LaunchStatus, VestingConfig, LaunchMetadata};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        metadata_pointer, transfer_fee, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+};
+use anchor_spl::token_interface::{self, InitializeMint2, Mint, TokenInterface};
+use spl_token_metadata_interface::state::TokenMetadata;
+use crate::state::{
+    LaunchConfig, PlatformConfig, PlatformStats, LaunchStatus, VestingConfig, GovernanceConfig, WithdrawalConfig,
+    LaunchMetadata, LaunchMetadataAccount, TokenExtensionsConfig, MintAuthorityPolicy, UnsoldTokenPolicy,
+    PositionTransferPolicy,
+    LaunchRegistry, RegistryBucket,
+};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::LaunchCreated;
+use crate::utils::update_creator_profile;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(launch_id: u64)]
+#[instruction(
+    name: String,
+    symbol: String,
+    decimals: u8,
+    total_supply: u64,
+    presale_price: u64,
+    min_contribution: u64,
+    max_contribution: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+    start_time: i64,
+    end_time: i64,
+    vesting_config: VestingConfig,
+    governance_config: GovernanceConfig,
+    withdrawal_config: WithdrawalConfig,
+    metadata: LaunchMetadata,
+)]
 pub struct CreateLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Created at the next ID allocated by the platform
     #[account(
         init,
         payer = creator,
         space = LaunchConfig::LEN,
-        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        seeds = [LAUNCH_SEED, platform_config.next_launch_id().to_le_bytes().as_ref()],
         bump
     )]
     pub launch_config: Account<'info, LaunchConfig>,
     
     #[account(
-        seeds = [PLATFORM_SEED, CONFIG_SEED],
-        bump = platform_config.bump
+        init,
+        payer = creator,
+        space = LaunchMetadataAccount::space(&metadata),
+        seeds = [LAUNCH_METADATA_SEED, platform_config.next_launch_id().to_le_bytes().as_ref()],
+        bump
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub launch_metadata: Account<'info, LaunchMetadataAccount>,
     
+    /// CHECK: Creator profile PDA, created on the creator's first launch and grown by each launch
     #[account(
-        init,
-        payer = creator,
-        mint::decimals = decimals,
-        mint::authority = launch_config,
+        mut,
+        seeds = [CREATOR_PROFILE_SEED, creator.key().as_ref()],
+        bump
     )]
-    pub token_mint: Account<'info, Mint>,
+    pub creator_profile: UncheckedAccount<'info>,
     
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
-        associated_token::mint = token_mint,
-        associated_token::authority = launch_config,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Pending.seed(),
+            LaunchRegistry::page_for(platform_config.next_launch_id()).to_le_bytes().as_ref()
+        ],
+        bump
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub pending_registry: Account<'info, LaunchRegistry>,
+    
+    /// CHECK: Platform treasury receiving the listing fee
+    #[account(
+        mut,
+        constraint = platform_treasury.key() == platform_config.treasury @ LaunchpadError::Unauthorized
+    )]
+    pub platform_treasury: AccountInfo<'info>,
+    
+    /// CHECK: Either a new mint keypair (signer), created under `token_program` with the
+    /// requested extensions, or an existing mint whose supply is deposited with `fund_launch`
+    #[account(mut)]
+    pub token_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Launch vault, created as the launch's associated token account in the handler
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &launch_config.key(),
+            &token_mint.key(),
+            &token_program.key(),
+        )
+    )]
+    pub token_vault: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub creator: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -50,7 +129,6 @@ pub struct CreateLaunch<'info> {
 
 pub fn create_launch(
     ctx: Context<CreateLaunch>,
-    launch_id: u64,
     name: String,
     symbol: String,
     decimals: u8,
@@ -63,7 +141,14 @@ pub fn create_launch(
     start_time: i64,
     end_time: i64,
     vesting_config: VestingConfig,
+    governance_config: GovernanceConfig,
+    withdrawal_config: WithdrawalConfig,
     metadata: LaunchMetadata,
+    token_extensions: TokenExtensionsConfig,
+    mint_authority_policy: MintAuthorityPolicy,
+    unsold_token_policy: UnsoldTokenPolicy,
+    position_transfer_policy: PositionTransferPolicy,
+    position_nfts: bool,
 ) -> Result<()> {
     let platform_config = &ctx.accounts.platform_config;
     
@@ -86,12 +171,102 @@ pub fn create_launch(
         start_time,
         end_time,
         &vesting_config,
+        &governance_config,
+        &withdrawal_config,
         &metadata,
         platform_config,
     )?;
 
+    // Extensions are only available on Token-2022 mints
+    let is_token_2022 = ctx.accounts.token_program.key() == spl_token_2022::ID;
+    token_extensions.validate(is_token_2022)?;
+    unsold_token_policy.validate()?;
+
+    let listing_fee = platform_config.listing_fee;
+    let creator_bond = platform_config.creator_bond_amount;
+
+    // The launch accounts were derived from the next ID, so this allocates the same one
+    let launch_id = ctx.accounts.platform_config.allocate_launch_id()?;
+
+    // Charge the listing fee to the platform treasury
+    if listing_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.platform_treasury.to_account_info(),
+                },
+            ),
+            listing_fee,
+        )?;
+    }
+    ctx.accounts.platform_stats.record_launch_created(listing_fee)?;
+
+    // Escrow the refundable creator bond in the launch account
+    if creator_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.launch_config.to_account_info(),
+                },
+            ),
+            creator_bond,
+        )?;
+    }
+
+    // An existing mint is sold from deposited inventory instead of being minted
+    let uses_existing_mint = !ctx.accounts.token_mint.data_is_empty();
+    mint_authority_policy.validate(uses_existing_mint, token_extensions.non_transferable)?;
+    if uses_existing_mint {
+        validate_existing_mint(&ctx, decimals, &token_extensions)?;
+    } else {
+        if !ctx.accounts.token_mint.is_signer {
+            return Err(ErrorCode::AccountNotSigner.into());
+        }
+        initialize_launch_mint(&ctx, launch_id, &name, &symbol, decimals, &token_extensions)?;
+    }
+
+    // The vault address is public, so tolerate it having been created already
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.creator.to_account_info(),
+            associated_token: ctx.accounts.token_vault.to_account_info(),
+            authority: ctx.accounts.launch_config.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    // Project metadata lives in its own account, sized to fit
+    let launch_metadata = &mut ctx.accounts.launch_metadata;
+    launch_metadata.launch_id = launch_id;
+    launch_metadata.metadata = metadata;
+    launch_metadata.updates_allowed = false;
+    launch_metadata.updated_at = Clock::get()?.unix_timestamp;
+    launch_metadata.bump = ctx.bumps.launch_metadata;
+    launch_metadata.version = LAUNCH_METADATA_VERSION;
+
+    // List the launch for discovery, in the pending bucket and on the creator's profile
+    ctx.accounts.pending_registry.list(
+        RegistryBucket::Pending,
+        launch_id,
+        ctx.bumps.pending_registry,
+    );
+    update_creator_profile(
+        &ctx.accounts.creator_profile.to_account_info(),
+        ctx.accounts.creator.key(),
+        ctx.bumps.creator_profile,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program,
+        |creator_profile| creator_profile.record_launch(launch_id),
+    )?;
+
     let launch_config = &mut ctx.accounts.launch_config;
-    let current_time = Clock::get()?.unix_timestamp;
 
     // Initialize launch configuration
     launch_config.creator = ctx.accounts.creator.key();
@@ -112,20 +287,222 @@ pub fn create_launch(
     launch_config.contributor_count = 0;
     launch_config.status = LaunchStatus::Pending;
     launch_config.vesting_config = vesting_config;
-    launch_config.metadata = metadata;
+    launch_config.creator_bond = creator_bond;
+    launch_config.referred_volume = 0;
+    launch_config.referral_pool = 0;
+    launch_config.uses_milestones = false;
+    launch_config.governance_config = governance_config;
+    launch_config.payout_address = ctx.accounts.creator.key();
+    launch_config.proposal_count = 0;
+    launch_config.open_investor_accounts = 0;
+    launch_config.withdrawal_config = withdrawal_config;
+    launch_config.refunds_swept = false;
+    launch_config.token_program = ctx.accounts.token_program.key();
+    launch_config.token_extensions = token_extensions;
+    launch_config.uses_existing_mint = uses_existing_mint;
+    launch_config.deposited_tokens = 0;
+    launch_config.mint_authority_policy = mint_authority_policy;
+    launch_config.unsold_token_policy = unsold_token_policy;
+    launch_config.position_transfer_policy = position_transfer_policy;
+    launch_config.position_nfts = position_nfts;
+    launch_config.reserved_allocation = 0;
+    launch_config.finalized = false;
     launch_config.bump = ctx.bumps.launch_config;
+    launch_config.version = LAUNCH_CONFIG_VERSION;
 
-    msg!(
-        "Launch created: ID {}, Token: {}, Creator: {}",
+    emit_cpi!(LaunchCreated {
         launch_id,
-        ctx.accounts.token_mint.key(),
-        ctx.accounts.creator.key()
-    );
+        creator: launch_config.creator,
+        token_mint: launch_config.token_mint,
+        token_program: launch_config.token_program,
+        uses_existing_mint: launch_config.uses_existing_mint,
+        total_supply,
+        presale_price,
+        soft_cap,
+        hard_cap,
+        start_time,
+        end_time,
+        listing_fee,
+        creator_bond,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
 
-fn validate_launch_parameters(
+/// Check an existing mint can back the launch as described
+fn validate_existing_mint(
+    ctx: &Context<CreateLaunch>,
+    decimals: u8,
+    token_extensions: &TokenExtensionsConfig,
+) -> Result<()> {
+    if *ctx.accounts.token_mint.owner != ctx.accounts.token_program.key() {
+        return Err(LaunchpadError::InvalidTokenProgram.into());
+    }
+
+    let mint_data = ctx.accounts.token_mint.try_borrow_data()?;
+    let token_mint = Mint::try_deserialize(&mut &mint_data[..])?;
+    if token_mint.decimals != decimals {
+        return Err(LaunchpadError::InvalidTokenParameters.into());
+    }
+
+    // A freeze authority could freeze the vault or investors' tokens
+    if token_mint.freeze_authority.is_some() {
+        return Err(LaunchpadError::MintHasFreezeAuthority.into());
+    }
+
+    // Only the extensions launches know how to handle are accepted. Non-transferable
+    // tokens could never be deposited, and withheld transfer fees would keep the vault
+    // from being closed.
+    if ctx.accounts.token_program.key() == spl_token_2022::ID {
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        let supported = [
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenMetadata,
+        ];
+        if mint.get_extension_types()?.iter().any(|extension| !supported.contains(extension)) {
+            return Err(LaunchpadError::UnsupportedMintExtension.into());
+        }
+    }
+
+    // Extensions can only be configured on mints created by the launch
+    if token_extensions.is_enabled() {
+        return Err(LaunchpadError::InvalidTokenExtensions.into());
+    }
+
+    Ok(())
+}
+
+/// Allocate the mint, initialize its extensions, then the mint itself.
+/// Embedded metadata is written last since it needs an initialized mint.
+fn initialize_launch_mint(
+    ctx: &Context<CreateLaunch>,
+    launch_id: u64,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    token_extensions: &TokenExtensionsConfig,
+) -> Result<()> {
+    let token_program_id = ctx.accounts.token_program.key();
+    let mint_key = ctx.accounts.token_mint.key();
+    let launch_key = ctx.accounts.launch_config.key();
+    let creator_key = ctx.accounts.creator.key();
+
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &token_extensions.extension_types(),
+    )?;
+
+    // Embedded metadata is reallocated into the mint, so fund it up front
+    let metadata_space = if token_extensions.embedded_metadata {
+        TokenMetadata {
+            mint: mint_key,
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: token_extensions.metadata_uri.clone(),
+            ..Default::default()
+        }
+        .tlv_size_of()?
+    } else {
+        0
+    };
+    let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        lamports,
+        space as u64,
+        &token_program_id,
+    )?;
+
+    let mint_accounts = [
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    ];
+
+    if token_extensions.embedded_metadata {
+        let ix = metadata_pointer::instruction::initialize(
+            &token_program_id,
+            &mint_key,
+            Some(launch_key),
+            Some(mint_key),
+        )?;
+        invoke_signed(&ix, &mint_accounts, &[])?;
+    }
+
+    if token_extensions.has_transfer_fee() {
+        // Withheld fees belong to the creator
+        let ix = transfer_fee::instruction::initialize_transfer_fee_config(
+            &token_program_id,
+            &mint_key,
+            Some(&launch_key),
+            Some(&creator_key),
+            token_extensions.transfer_fee_basis_points,
+            token_extensions.maximum_fee,
+        )?;
+        invoke_signed(&ix, &mint_accounts, &[])?;
+    }
+
+    if token_extensions.non_transferable {
+        let ix = spl_token_2022::instruction::initialize_non_transferable_mint(
+            &token_program_id,
+            &mint_key,
+        )?;
+        invoke_signed(&ix, &mint_accounts, &[])?;
+    }
+
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        decimals,
+        &launch_key,
+        None,
+    )?;
+
+    if token_extensions.embedded_metadata {
+        let launch_id_bytes = launch_id.to_le_bytes();
+        let seeds = &[
+            LAUNCH_SEED,
+            launch_id_bytes.as_ref(),
+            &[ctx.bumps.launch_config],
+        ];
+
+        let ix = spl_token_metadata_interface::instruction::initialize(
+            &token_program_id,
+            &mint_key,
+            &creator_key,
+            &mint_key,
+            &launch_key,
+            name.to_string(),
+            symbol.to_string(),
+            token_extensions.metadata_uri.clone(),
+        );
+        invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.launch_config.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            &[&seeds[..]],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Validate launch parameters; shared with `update_launch`
+pub(crate) fn validate_launch_parameters(
     name: &str,
     symbol: &str,
     decimals: u8,
@@ -138,6 +515,8 @@ fn validate_launch_parameters(
     start_time: i64,
     end_time: i64,
     vesting_config: &VestingConfig,
+    governance_config: &GovernanceConfig,
+    withdrawal_config: &WithdrawalConfig,
     metadata: &LaunchMetadata,
     platform_config: &PlatformConfig,
 ) -> Result<()> {
@@ -159,6 +538,16 @@ fn validate_launch_parameters(
         return Err(LaunchpadError::InvalidTokenParameters.into());
     }
 
+    // A sale up to the hard cap must fit within the total supply
+    let hard_cap_allocation = (hard_cap as u128)
+        .checked_mul(10_u128.pow(decimals as u32))
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_div(presale_price as u128)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if hard_cap_allocation > total_supply as u128 {
+        return Err(LaunchpadError::InvalidTokenParameters.into());
+    }
+
     // Validate contribution limits
     if min_contribution == 0 || max_contribution == 0 {
         return Err(LaunchpadError::InvalidTokenParameters.into());
@@ -190,33 +579,14 @@ fn validate_launch_parameters(
     // Validate vesting configuration
     vesting_config.validate()?;
 
+    // Validate governance configuration
+    governance_config.validate()?;
+
+    // Validate withdrawal configuration
+    withdrawal_config.validate()?;
+
     // Validate metadata lengths
-    validate_metadata_lengths(metadata)?;
+    metadata.validate()?;
 
     Ok(())
 }
-
-fn validate_metadata_lengths(metadata: &LaunchMetadata) -> Result<()> {
-    if metadata.description.len() > MAX_DESCRIPTION_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.website.len() > MAX_URL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.twitter.len() > MAX_SOCIAL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.telegram.len() > MAX_SOCIAL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.discord.len() > MAX_SOCIAL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.logo_uri.len() > MAX_URL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    if metadata.documentation.len() > MAX_URL_LENGTH {
-        return Err(LaunchpadError::MetadataTooLong.into());
-    }
-    Ok(())
-}
\ No newline at end of file
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    self, get_associated_token_address_with_program_id, AssociatedToken, Create,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus, PlatformStats, LedgerEntryKind};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::TokensDistributed;
+use crate::utils::{
+    authorize_crank_recipient, optional_remaining_account, record_ledger_entry, release_tokens,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct DistributeTokens<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(mut, address = launch_config.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = launch_config,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Pays rent for any investor token accounts and ledger pages that need creating
+    #[account(mut)]
+    pub distributor: Signer<'info>,
+    
+    #[account(
+        constraint = token_program.key() == launch_config.token_program @ LaunchpadError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfer currently claimable tokens to a batch of investors.
+/// `remaining_accounts` holds `(investor_account, recipient, recipient_token_account, contribution_ledger,
+/// position_token_account)` tuples. The recipient is the wallet that contributed, or the NFT holder for
+/// NFT positions. The program ID stands in for a ledger page or token account the investor does not have.
+pub fn distribute_tokens<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeTokens<'info>>,
+    launch_id: u64,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if launch was successful
+    if launch_config.status != LaunchStatus::Successful {
+        return Err(LaunchpadError::LaunchNotApproved.into());
+    }
+
+    if ctx.remaining_accounts.is_empty() || ctx.remaining_accounts.len() % 5 != 0 {
+        return Err(LaunchpadError::InvalidRemainingAccounts.into());
+    }
+
+    let mut distributed_count: u32 = 0;
+    let mut distributed_total: u64 = 0;
+
+    for accounts in ctx.remaining_accounts.chunks_exact(5) {
+        let investor_account_info = &accounts[0];
+        let recipient_info = &accounts[1];
+        let recipient_token_info = &accounts[2];
+        let ledger_info = optional_remaining_account(&accounts[3]);
+        let position_token_info = optional_remaining_account(&accounts[4]);
+
+        // Ownership and discriminator are checked on deserialization
+        let mut investor_account: Account<InvestorAccount> = Account::try_from(investor_account_info)?;
+
+        if investor_account.launch_id != launch_id {
+            return Err(LaunchpadError::InvalidRemainingAccounts.into());
+        }
+
+        // NFT positions are paid to whoever holds the NFT
+        authorize_crank_recipient(&investor_account, &recipient_info.key(), position_token_info)?;
+
+        // Tokens always go to the recipient's associated token account
+        let expected_token_account = get_associated_token_address_with_program_id(
+            &recipient_info.key(),
+            &ctx.accounts.token_mint.key(),
+            &ctx.accounts.token_program.key(),
+        );
+        if recipient_token_info.key() != expected_token_account {
+            return Err(LaunchpadError::InvalidRemainingAccounts.into());
+        }
+
+        let claimable_amount = investor_account.calculate_claimable_amount(
+            current_time,
+            &launch_config.vesting_config,
+            launch_config.end_time, // Use launch end time as vesting start
+        )?;
+
+        // Skip investors with nothing vested since their last claim
+        if claimable_amount == 0 {
+            continue;
+        }
+
+        // Create the recipient's token account if missing, paid by the distributor
+        if recipient_token_info.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                Create {
+                    payer: ctx.accounts.distributor.to_account_info(),
+                    associated_token: recipient_token_info.clone(),
+                    authority: recipient_info.clone(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        release_tokens(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.token_vault.to_account_info(),
+            recipient_token_info,
+            &ctx.accounts.launch_config,
+            claimable_amount,
+        )?;
+
+        investor_account.update_claimed_amount(claimable_amount, current_time)?;
+        record_ledger_entry(
+            ledger_info,
+            &mut investor_account,
+            &ctx.accounts.distributor.to_account_info(),
+            &ctx.accounts.system_program,
+            LedgerEntryKind::Claim,
+            claimable_amount,
+            launch_config.presale_price,
+        )?;
+        investor_account.exit(&crate::ID)?;
+
+        emit_cpi!(TokensDistributed {
+            launch_id,
+            investor: recipient_info.key(),
+            amount: claimable_amount,
+            distributor: ctx.accounts.distributor.key(),
+            timestamp: current_time,
+        });
+
+        distributed_count = distributed_count
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        distributed_total = distributed_total
+            .checked_add(claimable_amount)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+
+    ctx.accounts.platform_stats.record_tokens_claimed(distributed_total)?;
+
+    msg!(
+        "Tokens distributed for launch {}: {} investors, {} tokens",
+        launch_id,
+        distributed_count,
+        distributed_total
+    );
+
+    Ok(())
+}
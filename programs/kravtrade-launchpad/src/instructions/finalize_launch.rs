@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, MintTo};
-use crate::state::{LaunchConfig, PlatformConfig, LaunchStatus};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{self, Burn, Mint, SetAuthority, TokenAccount, TokenInterface};
+use crate::state::{
+    LaunchConfig, PlatformConfig, PlatformStats, InsuranceFund, MilestoneSchedule, LaunchStatus, UnsoldTokenPolicy,
+    LaunchRegistry, RegistryBucket,
+};
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::LaunchFinalized;
+use crate::utils::{
+    load_account, mint_launch_tokens, release_tokens, store_account, transfer_from_treasury, update_creator_profile,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(launch_id: u64)]
 pub struct FinalizeLaunch<'info> {
@@ -15,7 +24,6 @@ pub struct FinalizeLaunch<'info> {
     pub launch_config: Account<'info, LaunchConfig>,
     
     #[account(
-        mut,
         seeds = [PLATFORM_SEED, CONFIG_SEED],
         bump = platform_config.bump
     )]
@@ -23,16 +31,44 @@ pub struct FinalizeLaunch<'info> {
     
     #[account(
         mut,
-        mint::authority = launch_config,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    #[account(
+        mut,
+        address = launch_config.token_mint,
+        mint::token_program = token_program,
     )]
-    pub token_mint: Account<'info, token::Mint>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         associated_token::mint = token_mint,
         associated_token::authority = launch_config,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Receives surplus inventory of existing-mint launches, the creator
+    /// allocation of minted launches and unsold tokens returned to the creator;
+    /// required whenever any of them is non-zero
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Receives unsold tokens when the launch adds them to liquidity
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub liquidity_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     /// CHECK: Treasury account holding contributions
     #[account(
@@ -43,19 +79,82 @@ pub struct FinalizeLaunch<'info> {
     pub treasury_account: AccountInfo<'info>,
     
     /// CHECK: Platform treasury for fee collection
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = platform_treasury.key() == platform_config.treasury @ LaunchpadError::Unauthorized
+    )]
     pub platform_treasury: AccountInfo<'info>,
     
+    /// CHECK: Insurance fund PDA receiving the insurance share of the platform fee once it
+    /// has been initialized; until then the share stays with the platform treasury
+    #[account(
+        mut,
+        seeds = [INSURANCE_SEED],
+        bump
+    )]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator profile PDA credited with a successful launch, created if missing
+    #[account(
+        mut,
+        seeds = [CREATOR_PROFILE_SEED, launch_config.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Active.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub active_registry: Account<'info, LaunchRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = LaunchRegistry::LEN,
+        seeds = [
+            LAUNCH_REGISTRY_SEED,
+            RegistryBucket::Ended.seed(),
+            LaunchRegistry::page_for(launch_id).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub ended_registry: Account<'info, LaunchRegistry>,
+    
+    /// Required when the launch escrows proceeds behind milestones
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+    
+    /// CHECK: Address receiving the creator proceeds
+    #[account(
+        mut,
+        constraint = payout_account.key() == launch_config.payout_address @ LaunchpadError::Unauthorized
+    )]
+    pub payout_account: AccountInfo<'info>,
+    
     #[account(mut)]
     pub creator: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = token_program.key() == launch_config.token_program @ LaunchpadError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
-    let launch_config = &mut ctx.accounts.launch_config;
-    let platform_config = &mut ctx.accounts.platform_config;
+    let launch_config = &ctx.accounts.launch_config;
     let current_time = Clock::get()?.unix_timestamp;
 
     // Validate finalization eligibility
@@ -66,16 +165,20 @@ pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
         return Err(LaunchpadError::Unauthorized.into());
     }
 
-    // Determine launch outcome
+    // Either way the launch moves from the active to the ended bucket
+    let launch_id = launch_config.launch_id;
     let is_successful = launch_config.has_reached_soft_cap();
+    ctx.accounts.active_registry.unlist(RegistryBucket::Active, launch_id, ctx.bumps.active_registry);
+    ctx.accounts.ended_registry.list(RegistryBucket::Ended, launch_id, ctx.bumps.ended_registry);
+
+    // Determine launch outcome
     
     if is_successful {
         // Launch successful - mint tokens and distribute funds
-        finalize_successful_launch(ctx, launch_config, platform_config)?;
+        finalize_successful_launch(ctx)?;
     } else {
-        // Launch failed - mark for refunds
-        launch_config.status = LaunchStatus::Failed;
-        msg!("Launch {} failed to reach soft cap", launch_config.launch_id);
+        // Launch failed - mark for refunds and slash the creator bond
+        finalize_failed_launch(ctx)?;
     }
 
     Ok(())
@@ -85,8 +188,11 @@ fn validate_finalization_eligibility(
     launch_config: &LaunchConfig,
     current_time: i64,
 ) -> Result<()> {
-    // Check if launch is in correct status
-    if launch_config.status != LaunchStatus::Active && launch_config.status != LaunchStatus::Successful {
+    // Check if launch is in correct status. Reaching the hard cap marks a launch
+    // successful before it is finalized.
+    if launch_config.finalized
+        || (launch_config.status != LaunchStatus::Active && launch_config.status != LaunchStatus::Successful)
+    {
         return Err(LaunchpadError::LaunchAlreadyFinalized.into());
     }
 
@@ -101,67 +207,383 @@ fn validate_finalization_eligibility(
     Ok(())
 }
 
-fn finalize_successful_launch(
-    ctx: Context<FinalizeLaunch>,
-    launch_config: &mut LaunchConfig,
-    platform_config: &mut PlatformConfig,
-) -> Result<()> {
+fn finalize_successful_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let platform_config = &ctx.accounts.platform_config;
     let total_raised = launch_config.total_raised;
     
     // Calculate platform fee
     let platform_fee = platform_config.calculate_platform_fee(total_raised)?;
     let creator_amount = total_raised.saturating_sub(platform_fee);
 
-    // Calculate total tokens to mint for presale
+    // Carve the referral pool out of the platform fee; it stays in the treasury
+    let referral_pool = if launch_config.referred_volume > 0 {
+        platform_config.calculate_referral_share(platform_fee)?
+    } else {
+        0
+    };
+    let insurance_fund = ctx.accounts.insurance_fund.to_account_info();
+    let insurance_initialized = insurance_fund.owner == &crate::ID && !insurance_fund.data_is_empty();
+    let insurance_amount = if insurance_initialized {
+        platform_config.calculate_insurance_share(platform_fee)?
+    } else {
+        0
+    };
+    let treasury_fee = platform_fee
+        .saturating_sub(referral_pool)
+        .saturating_sub(insurance_amount);
+
+    // Calculate tokens sold and the unsold gap up to the hard cap allocation
     let total_tokens_for_presale = launch_config.calculate_token_allocation(total_raised)?;
+    let hard_cap_tokens = launch_config.presale_allocation()?;
+    let unsold_tokens = hard_cap_tokens
+        .checked_sub(total_tokens_for_presale)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
 
-    // Mint tokens to vault for distribution
-    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
-    let seeds = &[
-        LAUNCH_SEED,
-        launch_id_bytes.as_ref(),
-        &[launch_config.bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
+    // Existing-mint launches sell from deposited inventory and return any surplus;
+    // otherwise mint the sold and creator supply
+    if launch_config.uses_existing_mint {
+        let surplus_tokens = launch_config.deposited_tokens
+            .checked_sub(hard_cap_tokens)
+            .ok_or(LaunchpadError::LaunchNotFunded)?;
+        return_deposited_tokens(ctx.accounts, surplus_tokens)?;
+    } else {
+        mint_full_supply(ctx.accounts, total_tokens_for_presale, hard_cap_tokens)?;
+    }
 
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.token_vault.to_account_info(),
-                authority: ctx.accounts.launch_config.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        total_tokens_for_presale,
-    )?;
+    // Burn, return or pool the unsold tokens, then settle the mint authority
+    settle_unsold_tokens(ctx.accounts, unsold_tokens)?;
+    if !launch_config.uses_existing_mint {
+        apply_mint_authority_policy(ctx.accounts)?;
+    }
 
     // Transfer platform fee to platform treasury
-    if platform_fee > 0 {
-        **ctx.accounts.treasury_account.try_borrow_mut_lamports()? -= platform_fee;
-        **ctx.accounts.platform_treasury.try_borrow_mut_lamports()? += platform_fee;
+    let launch_id = launch_config.launch_id;
+    let treasury_bump = ctx.bumps.treasury_account;
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.platform_treasury,
+        launch_id,
+        treasury_bump,
+        treasury_fee,
+    )?;
+
+    // Transfer the insurance share to the insurance fund, if one has been initialized
+    if insurance_initialized {
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            &insurance_fund,
+            launch_id,
+            treasury_bump,
+            insurance_amount,
+        )?;
+        let mut fund: InsuranceFund = load_account(&insurance_fund)?;
+        fund.record_deposit(insurance_amount)?;
+        store_account(&insurance_fund, &fund)?;
     }
 
-    // Transfer remaining funds to creator
-    if creator_amount > 0 {
-        **ctx.accounts.treasury_account.try_borrow_mut_lamports()? -= creator_amount;
-        **ctx.accounts.creator.try_borrow_mut_lamports()? += creator_amount;
+    // Transfer remaining funds to creator, or hold them in escrow for milestones
+    if launch_config.uses_milestones {
+        let milestone_schedule = ctx.accounts.milestone_schedule
+            .as_mut()
+            .ok_or(LaunchpadError::MilestoneScheduleMissing)?;
+        milestone_schedule.escrowed_amount = creator_amount;
+    } else {
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            &ctx.accounts.payout_account,
+            launch_id,
+            treasury_bump,
+            creator_amount,
+        )?;
+    }
+
+    // Return the creator bond
+    let launch_account = ctx.accounts.launch_config.to_account_info();
+    let launch_config = &mut ctx.accounts.launch_config;
+    let bond_returned = launch_config.release_creator_bond(
+        &launch_account,
+        &ctx.accounts.creator.to_account_info(),
+    )?;
+
+    // Only the sold inventory remains deposited
+    if launch_config.uses_existing_mint {
+        launch_config.deposited_tokens = total_tokens_for_presale;
     }
 
     // Update launch status
+    launch_config.referral_pool = referral_pool;
     launch_config.status = LaunchStatus::Successful;
+    launch_config.finalized = true;
 
     // Update platform statistics
-    platform_config.update_stats(total_raised, platform_fee)?;
+    ctx.accounts.platform_stats.record_success(total_raised, treasury_fee)?;
+    update_creator_profile(
+        &ctx.accounts.creator_profile.to_account_info(),
+        launch_config.creator,
+        ctx.bumps.creator_profile,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program,
+        |creator_profile| creator_profile.record_success(total_raised),
+    )?;
 
-    msg!(
-        "Launch {} finalized successfully. Raised: {} lamports, Fee: {} lamports, Creator: {} lamports",
-        launch_config.launch_id,
+    emit_cpi!(LaunchFinalized {
+        launch_id: launch_config.launch_id,
+        creator: launch_config.creator,
+        successful: true,
         total_raised,
         platform_fee,
-        creator_amount
+        referral_pool,
+        insurance_amount,
+        creator_amount,
+        tokens_sold: total_tokens_for_presale,
+        unsold_tokens,
+        bond_amount: bond_returned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn finalize_failed_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
+    // Nothing was sold, so the whole deposit goes back to the creator
+    if ctx.accounts.launch_config.uses_existing_mint {
+        return_deposited_tokens(ctx.accounts, ctx.accounts.launch_config.deposited_tokens)?;
+        ctx.accounts.launch_config.deposited_tokens = 0;
+    }
+
+    // Slash the creator bond to the platform treasury
+    let launch_account = ctx.accounts.launch_config.to_account_info();
+    let launch_config = &mut ctx.accounts.launch_config;
+    let bond_slashed = launch_config.release_creator_bond(
+        &launch_account,
+        &ctx.accounts.platform_treasury,
+    )?;
+    ctx.accounts.platform_stats.record_failure(bond_slashed)?;
+
+    launch_config.status = LaunchStatus::Failed;
+    launch_config.finalized = true;
+
+    emit_cpi!(LaunchFinalized {
+        launch_id: launch_config.launch_id,
+        creator: launch_config.creator,
+        successful: false,
+        total_raised: launch_config.total_raised,
+        platform_fee: 0,
+        referral_pool: 0,
+        insurance_amount: 0,
+        creator_amount: 0,
+        tokens_sold: 0,
+        unsold_tokens: 0,
+        bond_amount: bond_slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Send deposited inventory of an existing-mint launch back to the creator
+fn return_deposited_tokens(accounts: &FinalizeLaunch, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let creator_token_account = accounts.creator_token_account
+        .as_ref()
+        .ok_or(LaunchpadError::CreatorTokenAccountMissing)?;
+
+    release_tokens(
+        &accounts.token_program.to_account_info(),
+        &accounts.token_mint.to_account_info(),
+        &accounts.token_vault.to_account_info(),
+        &creator_token_account.to_account_info(),
+        &accounts.launch_config,
+        amount,
+    )?;
+
+    msg!(
+        "Returned {} deposited tokens to creator for launch {}",
+        amount,
+        accounts.launch_config.launch_id
+    );
+
+    Ok(())
+}
+
+/// Mint the sold presale tokens to the vault and the supply beyond the hard cap
+/// allocation to the creator. Non-transferable presale tokens are minted as investors
+/// claim instead, and unsold tokens are left to the unsold token policy.
+fn mint_full_supply(accounts: &FinalizeLaunch, presale_tokens: u64, hard_cap_tokens: u64) -> Result<()> {
+    let launch_config = &accounts.launch_config;
+
+    if !launch_config.token_extensions.non_transferable {
+        mint_launch_tokens(
+            &accounts.token_program.to_account_info(),
+            &accounts.token_mint.to_account_info(),
+            &accounts.token_vault.to_account_info(),
+            launch_config,
+            presale_tokens,
+        )?;
+    }
+
+    let creator_tokens = launch_config.total_supply
+        .checked_sub(hard_cap_tokens)
+        .ok_or(LaunchpadError::SupplyCapExceeded)?;
+    if creator_tokens > 0 {
+        let creator_token_account = accounts.creator_token_account
+            .as_ref()
+            .ok_or(LaunchpadError::CreatorTokenAccountMissing)?;
+
+        mint_launch_tokens(
+            &accounts.token_program.to_account_info(),
+            &accounts.token_mint.to_account_info(),
+            &creator_token_account.to_account_info(),
+            launch_config,
+            creator_tokens,
+        )?;
+    }
+
+    msg!(
+        "Minted {} presale tokens and {} creator tokens for launch {}",
+        presale_tokens,
+        creator_tokens,
+        launch_config.launch_id
+    );
+
+    Ok(())
+}
+
+/// Apply the launch's unsold token policy to the gap between the hard cap allocation and sales
+fn settle_unsold_tokens(accounts: &FinalizeLaunch, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let launch_config = &accounts.launch_config;
+    let destination = match &launch_config.unsold_token_policy {
+        UnsoldTokenPolicy::Burn => {
+            // Minted launches never mint the unsold tokens in the first place
+            if launch_config.uses_existing_mint {
+                burn_vault_tokens(accounts, amount)?;
+            }
+            msg!("Burned {} unsold tokens for launch {}", amount, launch_config.launch_id);
+            return Ok(());
+        }
+        UnsoldTokenPolicy::ReturnToCreator => accounts.creator_token_account
+            .as_ref()
+            .ok_or(LaunchpadError::CreatorTokenAccountMissing)?,
+        UnsoldTokenPolicy::AddToLiquidity { liquidity_account } => {
+            let liquidity_token_account = accounts.liquidity_token_account
+                .as_ref()
+                .ok_or(LaunchpadError::LiquidityAccountMissing)?;
+            if liquidity_token_account.key() != *liquidity_account {
+                return Err(LaunchpadError::LiquidityAccountMissing.into());
+            }
+            liquidity_token_account
+        }
+    };
+
+    if launch_config.uses_existing_mint {
+        release_tokens(
+            &accounts.token_program.to_account_info(),
+            &accounts.token_mint.to_account_info(),
+            &accounts.token_vault.to_account_info(),
+            &destination.to_account_info(),
+            launch_config,
+            amount,
+        )?;
+    } else {
+        mint_launch_tokens(
+            &accounts.token_program.to_account_info(),
+            &accounts.token_mint.to_account_info(),
+            &destination.to_account_info(),
+            launch_config,
+            amount,
+        )?;
+    }
+
+    msg!(
+        "Sent {} unsold tokens to {} for launch {}",
+        amount,
+        destination.key(),
+        launch_config.launch_id
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Burn tokens held in the launch vault, signed by the launch PDA
+fn burn_vault_tokens(accounts: &FinalizeLaunch, amount: u64) -> Result<()> {
+    let launch_config = &accounts.launch_config;
+    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
+    let seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: accounts.token_mint.to_account_info(),
+                from: accounts.token_vault.to_account_info(),
+                authority: launch_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+/// Revoke or hand off the mint and freeze authority held by the launch PDA
+fn apply_mint_authority_policy(accounts: &FinalizeLaunch) -> Result<()> {
+    let launch_config = &accounts.launch_config;
+    let new_authority = match launch_config.mint_authority_policy.new_authority() {
+        Some(new_authority) => new_authority,
+        None => return Ok(()),
+    };
+
+    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
+    let seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let launch_key = launch_config.key();
+    let mut authority_types = vec![AuthorityType::MintTokens];
+    if accounts.token_mint.freeze_authority == Some(launch_key).into() {
+        authority_types.push(AuthorityType::FreezeAccount);
+    }
+
+    for authority_type in authority_types {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: launch_config.to_account_info(),
+                    account_or_mint: accounts.token_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            authority_type,
+            new_authority,
+        )?;
+    }
+
+    match new_authority {
+        Some(authority) => msg!(
+            "Mint authority for launch {} transferred to {}",
+            launch_config.launch_id,
+            authority
+        ),
+        None => msg!("Mint authority for launch {} revoked", launch_config.launch_id),
+    }
+
+    Ok(())
+}
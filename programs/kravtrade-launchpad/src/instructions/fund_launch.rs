@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{LaunchConfig, LaunchStatus};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct FundLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(address = launch_config.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = launch_config,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub creator: Signer<'info>,
+
+    #[account(
+        constraint = token_program.key() == launch_config.token_program @ LaunchpadError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn fund_launch(ctx: Context<FundLaunch>, launch_id: u64, amount: u64) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+
+    if !launch_config.uses_existing_mint {
+        return Err(LaunchpadError::ExistingMintRequired.into());
+    }
+
+    // Inventory is deposited before the sale is approved
+    if launch_config.status != LaunchStatus::Pending {
+        return Err(LaunchpadError::LaunchAlreadyFinalized.into());
+    }
+
+    if amount == 0 {
+        return Err(LaunchpadError::InvalidTokenParameters.into());
+    }
+
+    let balance_before = ctx.accounts.token_vault.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Count what arrived, which is less than `amount` if the mint charges a transfer fee
+    ctx.accounts.token_vault.reload()?;
+    let received = ctx.accounts.token_vault.amount
+        .checked_sub(balance_before)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    let launch_config = &mut ctx.accounts.launch_config;
+    launch_config.deposited_tokens = launch_config.deposited_tokens
+        .checked_add(received)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    msg!(
+        "Launch {} funded with {} tokens, {} of {} required deposited",
+        launch_id,
+        received,
+        launch_config.deposited_tokens,
+        launch_config.presale_allocation()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use crate::state::{
+    LaunchConfig, InvestorAccount, LaunchStatus, MilestoneSchedule, Proposal, ProposalKind,
+    ProposalStatus, ProposalVote,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::authorize_position_holder;
+
+// Create Proposal
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [
+            PROPOSAL_SEED,
+            launch_id.to_le_bytes().as_ref(),
+            launch_config.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    launch_id: u64,
+    kind: ProposalKind,
+) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Governance applies to launches that have completed their raise
+    if launch_config.status != LaunchStatus::Successful {
+        return Err(LaunchpadError::LaunchNotApproved.into());
+    }
+
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        &ctx.accounts.investor_account,
+        &ctx.accounts.proposer.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    // Only investors holding an allocation can propose
+    if ctx.accounts.investor_account.token_allocation == 0 {
+        return Err(LaunchpadError::NoVotingPower.into());
+    }
+
+    validate_proposal_kind(&kind, launch_config)?;
+
+    let proposal_id = launch_config.proposal_count;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.launch_id = launch_id;
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.kind = kind;
+    proposal.status = ProposalStatus::Voting;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.created_at = current_time;
+    proposal.voting_ends_at = current_time
+        .checked_add(launch_config.governance_config.voting_period)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    proposal.bump = ctx.bumps.proposal;
+
+    launch_config.proposal_count = proposal_id
+        .checked_add(1)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    msg!(
+        "Proposal {} created for launch {} by {}, voting ends at {}",
+        proposal_id,
+        launch_id,
+        ctx.accounts.proposer.key(),
+        proposal.voting_ends_at
+    );
+
+    Ok(())
+}
+
+fn validate_proposal_kind(kind: &ProposalKind, launch_config: &LaunchConfig) -> Result<()> {
+    match kind {
+        ProposalKind::ExtendVesting { additional_duration } => {
+            if *additional_duration <= 0 {
+                return Err(LaunchpadError::InvalidProposal.into());
+            }
+        }
+        ProposalKind::TriggerRefund => {
+            // Only milestone launches hold escrowed proceeds
+            if !launch_config.uses_milestones {
+                return Err(LaunchpadError::InvalidProposal.into());
+            }
+        }
+        ProposalKind::ReplacePayoutAddress { new_payout_address } => {
+            if *new_payout_address == Pubkey::default()
+                || *new_payout_address == launch_config.payout_address
+            {
+                return Err(LaunchpadError::InvalidProposal.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+// Cast Vote
+#[derive(Accounts)]
+#[instruction(launch_id: u64, proposal_id: u64)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, launch_id.to_le_bytes().as_ref(), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// One vote per position, whoever holds it
+    #[account(
+        init,
+        payer = investor,
+        space = ProposalVote::LEN,
+        seeds = [PROPOSAL_VOTE_SEED, proposal.key().as_ref(), investor_account.investor.as_ref()],
+        bump
+    )]
+    pub proposal_vote: Account<'info, ProposalVote>,
+
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cast_vote(
+    ctx: Context<CastVote>,
+    launch_id: u64,
+    proposal_id: u64,
+    approve: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if proposal.status != ProposalStatus::Voting || current_time > proposal.voting_ends_at {
+        return Err(LaunchpadError::ProposalVotingClosed.into());
+    }
+
+    // Positions represented by an NFT are controlled by its current holder
+    let investor_account = &ctx.accounts.investor_account;
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    // Voting weight is the investor's token allocation
+    if investor_account.token_allocation == 0 {
+        return Err(LaunchpadError::NoVotingPower.into());
+    }
+
+    // Allocation moved in after the proposal opened was already voted by the sender
+    let weight = investor_account.vote_weight_since(investor_account.token_allocation, proposal.created_at)?;
+    if weight == 0 {
+        return Err(LaunchpadError::PositionReceivedDuringVote.into());
+    }
+
+    if approve {
+        proposal.votes_for = proposal.votes_for
+            .checked_add(weight)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    } else {
+        proposal.votes_against = proposal.votes_against
+            .checked_add(weight)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+
+    let proposal_vote = &mut ctx.accounts.proposal_vote;
+    proposal_vote.investor = investor_account.investor;
+    proposal_vote.proposal = proposal.key();
+    proposal_vote.weight = weight;
+    proposal_vote.approve = approve;
+    proposal_vote.bump = ctx.bumps.proposal_vote;
+
+    msg!(
+        "Vote on proposal {} for launch {}: {} by {} with weight {}",
+        proposal_id,
+        launch_id,
+        if approve { "approve" } else { "reject" },
+        ctx.accounts.investor.key(),
+        weight
+    );
+
+    Ok(())
+}
+
+// Execute Proposal
+#[derive(Accounts)]
+#[instruction(launch_id: u64, proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, launch_id.to_le_bytes().as_ref(), proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Required for refund proposals
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_proposal(
+    ctx: Context<ExecuteProposal>,
+    launch_id: u64,
+    proposal_id: u64,
+) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let proposal = &mut ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if proposal.status != ProposalStatus::Voting {
+        return Err(LaunchpadError::ProposalVotingClosed.into());
+    }
+    if current_time <= proposal.voting_ends_at {
+        return Err(LaunchpadError::ProposalVotingInProgress.into());
+    }
+
+    // Quorum is measured against every token allocated in the presale
+    let total_voting_power = launch_config.calculate_token_allocation(launch_config.total_raised)?;
+    let quorum = launch_config.governance_config.calculate_quorum(total_voting_power)?;
+    let total_votes = proposal.votes_for
+        .checked_add(proposal.votes_against)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    let passed = total_votes >= quorum
+        && launch_config.governance_config.is_approved(proposal.votes_for, proposal.votes_against);
+
+    if !passed {
+        proposal.status = ProposalStatus::Defeated;
+
+        msg!(
+            "Proposal {} for launch {} defeated: {} for, {} against, quorum {}",
+            proposal_id,
+            launch_id,
+            proposal.votes_for,
+            proposal.votes_against,
+            quorum
+        );
+
+        return Ok(());
+    }
+
+    match proposal.kind {
+        ProposalKind::ExtendVesting { additional_duration } => {
+            launch_config.vesting_config.vesting_duration = launch_config.vesting_config.vesting_duration
+                .checked_add(additional_duration)
+                .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        }
+        ProposalKind::TriggerRefund => {
+            let milestone_schedule = ctx.accounts.milestone_schedule
+                .as_mut()
+                .ok_or(LaunchpadError::MilestoneScheduleMissing)?;
+
+            if milestone_schedule.refunds_enabled {
+                return Err(LaunchpadError::InvalidProposal.into());
+            }
+
+            // Cancel any open release vote, then freeze the remaining escrow for refunds
+            milestone_schedule.enable_refunds();
+        }
+        ProposalKind::ReplacePayoutAddress { new_payout_address } => {
+            launch_config.payout_address = new_payout_address;
+        }
+    }
+
+    proposal.status = ProposalStatus::Executed;
+
+    msg!(
+        "Proposal {} for launch {} executed by {}: {} for, {} against",
+        proposal_id,
+        launch_id,
+        ctx.accounts.executor.key(),
+        proposal.votes_for,
+        proposal.votes_against
+    );
+
+    Ok(())
+}
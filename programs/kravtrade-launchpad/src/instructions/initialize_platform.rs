@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::PlatformConfig;
+use crate::state::{PlatformConfig, PlatformStats};
 use crate::constants::*;
-use crate::errors::LaunchpadError;
+use crate::events::PlatformInitialized;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializePlatform<'info> {
     #[account(
@@ -14,6 +15,15 @@ pub struct InitializePlatform<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        init,
+        payer = admin,
+        space = PlatformStats::LEN,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
     
@@ -47,18 +57,34 @@ pub fn initialize_platform(
     platform_config.min_launch_duration = min_launch_duration;
     platform_config.max_launch_duration = max_launch_duration;
     platform_config.min_soft_cap = min_soft_cap;
+    platform_config.listing_fee = DEFAULT_LISTING_FEE;
+    platform_config.creator_bond_amount = DEFAULT_CREATOR_BOND;
+    platform_config.referral_fee_share = DEFAULT_REFERRAL_FEE_SHARE;
+    platform_config.insurance_fee_share = DEFAULT_INSURANCE_FEE_SHARE;
+    platform_config.milestone_threshold = DEFAULT_MILESTONE_THRESHOLD;
+    platform_config.refund_window = DEFAULT_REFUND_WINDOW;
     platform_config.is_paused = false;
     platform_config.total_launches = 0;
     platform_config.total_raised = 0;
     platform_config.total_fees_collected = 0;
     platform_config.bump = ctx.bumps.platform_config;
+    platform_config.version = PLATFORM_CONFIG_VERSION;
+    platform_config.last_launch_id = 0;
+    platform_config.stake_mint = Pubkey::default();
+    platform_config.staking_tiers = Vec::new();
 
-    msg!(
-        "Platform initialized with admin: {}, treasury: {}, fee: {}%",
-        ctx.accounts.admin.key(),
-        ctx.accounts.treasury.key(),
-        platform_fee_percentage as f64 / 100.0
-    );
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.set_inner(PlatformStats::from_config(platform_config, ctx.bumps.platform_stats));
+
+    emit_cpi!(PlatformInitialized {
+        admin: ctx.accounts.admin.key(),
+        treasury: ctx.accounts.treasury.key(),
+        platform_fee_percentage,
+        min_launch_duration,
+        max_launch_duration,
+        min_soft_cap,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
\ No newline at end of file
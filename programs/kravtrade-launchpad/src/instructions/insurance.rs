@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use crate::state::{LaunchConfig, PlatformConfig, InvestorAccount, InsuranceFund, InsuranceClaim, LaunchStatus};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::authorize_position_holder;
+
+// Initialize Insurance Fund
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceFund::LEN,
+        seeds = [INSURANCE_SEED],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.total_deposited = 0;
+    insurance_fund.total_committed = 0;
+    insurance_fund.total_paid = 0;
+    insurance_fund.bump = ctx.bumps.insurance_fund;
+
+    msg!(
+        "Insurance fund initialized by admin {}",
+        ctx.accounts.admin.key()
+    );
+
+    Ok(())
+}
+
+// Flag Launch
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct FlagLaunch<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceClaim::LEN,
+        seeds = [INSURANCE_CLAIM_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub insurance_claim: Account<'info, InsuranceClaim>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn flag_launch(
+    ctx: Context<FlagLaunch>,
+    launch_id: u64,
+    compensation_amount: u64,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+
+    // Only launches that paid out to the creator can be flagged
+    if launch_config.status != LaunchStatus::Successful {
+        return Err(LaunchpadError::LaunchNotApproved.into());
+    }
+
+    if compensation_amount == 0 {
+        return Err(LaunchpadError::NoCompensationAvailable.into());
+    }
+
+    // Reserve the compensation from the uncommitted fund balance
+    ctx.accounts.insurance_fund.commit(compensation_amount)?;
+
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+    insurance_claim.launch_id = launch_id;
+    insurance_claim.compensation_pool = compensation_amount;
+    insurance_claim.total_contributions = launch_config.total_raised;
+    insurance_claim.total_paid = 0;
+    insurance_claim.flagged_at = Clock::get()?.unix_timestamp;
+    insurance_claim.bump = ctx.bumps.insurance_claim;
+
+    msg!(
+        "Launch {} flagged by admin {}, compensation pool: {} lamports",
+        launch_id,
+        ctx.accounts.admin.key(),
+        compensation_amount
+    );
+
+    Ok(())
+}
+
+// Claim Insurance
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ClaimInsurance<'info> {
+    #[account(
+        mut,
+        seeds = [INSURANCE_CLAIM_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = insurance_claim.bump
+    )]
+    pub insurance_claim: Account<'info, InsuranceClaim>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_SEED],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(
+        mut,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    pub investor: Signer<'info>,
+}
+
+pub fn claim_insurance(ctx: Context<ClaimInsurance>, launch_id: u64) -> Result<()> {
+    let investor_account = &mut ctx.accounts.investor_account;
+
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    if investor_account.insurance_claimed {
+        return Err(LaunchpadError::AlreadyClaimed.into());
+    }
+
+    let compensation = ctx.accounts.insurance_claim
+        .calculate_compensation(investor_account.contribution_amount)?;
+
+    if compensation == 0 {
+        return Err(LaunchpadError::NoCompensationAvailable.into());
+    }
+
+    // Pay out of the insurance fund account
+    **ctx.accounts.insurance_fund.to_account_info().try_borrow_mut_lamports()? -= compensation;
+    **ctx.accounts.investor.try_borrow_mut_lamports()? += compensation;
+
+    ctx.accounts.insurance_fund.record_payout(compensation)?;
+
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+    insurance_claim.total_paid = insurance_claim.total_paid
+        .checked_add(compensation)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    investor_account.insurance_claimed = true;
+
+    msg!(
+        "Insurance compensation claimed: {} lamports by {} for launch {}",
+        compensation,
+        ctx.accounts.investor.key(),
+        launch_id
+    );
+
+    Ok(())
+}
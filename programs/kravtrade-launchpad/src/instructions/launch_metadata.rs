@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{LaunchConfig, PlatformConfig, LaunchStatus, LaunchMetadata, LaunchMetadataAccount};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+
+// Update Launch Metadata
+#[derive(Accounts)]
+#[instruction(launch_id: u64, metadata: LaunchMetadata)]
+pub struct UpdateLaunchMetadata<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LAUNCH_METADATA_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_metadata.bump,
+        realloc = LaunchMetadataAccount::space(&metadata),
+        realloc::payer = creator,
+        realloc::zero = false
+    )]
+    pub launch_metadata: Account<'info, LaunchMetadataAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_launch_metadata(
+    ctx: Context<UpdateLaunchMetadata>,
+    launch_id: u64,
+    metadata: LaunchMetadata,
+) -> Result<()> {
+    let launch_metadata = &mut ctx.accounts.launch_metadata;
+
+    // Metadata is frozen once investors can see the launch, unless the admin unlocks it
+    if ctx.accounts.launch_config.status != LaunchStatus::Pending && !launch_metadata.updates_allowed {
+        return Err(LaunchpadError::MetadataLocked.into());
+    }
+
+    metadata.validate()?;
+
+    // The account was resized to fit the new metadata before the handler ran
+    launch_metadata.metadata = metadata;
+    launch_metadata.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Metadata for launch {} updated by creator {}",
+        launch_id,
+        ctx.accounts.creator.key()
+    );
+
+    Ok(())
+}
+
+// Allow Metadata Updates
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct AllowMetadataUpdates<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_METADATA_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_metadata.bump
+    )]
+    pub launch_metadata: Account<'info, LaunchMetadataAccount>,
+    
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn allow_metadata_updates(
+    ctx: Context<AllowMetadataUpdates>,
+    launch_id: u64,
+    allowed: bool,
+) -> Result<()> {
+    ctx.accounts.launch_metadata.updates_allowed = allowed;
+
+    msg!(
+        "Metadata updates for launch {} {} by admin {}",
+        launch_id,
+        if allowed { "allowed" } else { "locked" },
+        ctx.accounts.admin.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,622 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::Mint;
+use crate::state::{
+    GovernanceConfig, LaunchConfig, LaunchMetadata, LaunchMetadataAccount, LaunchStatus,
+    InvestorAccount, MintAuthorityPolicy, PlatformConfig, PlatformStats, PositionTransferPolicy,
+    TokenExtensionsConfig, UnsoldTokenPolicy, VestingConfig, WithdrawalConfig,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::{create_program_account, resize_account, store_account};
+
+/// `PlatformConfig` as written before accounts were versioned
+#[derive(AnchorDeserialize)]
+struct PlatformConfigV0 {
+    admin: Pubkey,
+    treasury: Pubkey,
+    platform_fee_percentage: u16,
+    min_launch_duration: i64,
+    max_launch_duration: i64,
+    min_soft_cap: u64,
+    is_paused: bool,
+    total_launches: u64,
+    total_raised: u64,
+    total_fees_collected: u64,
+    bump: u8,
+}
+
+impl PlatformConfigV0 {
+    const LEN: usize = 8 + 32 + 32 + 2 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 1;
+
+    /// Rebuild the config in the current layout. Settings the baseline program did not
+    /// have start disabled, which is how it behaved.
+    fn into_current(self) -> PlatformConfig {
+        PlatformConfig {
+            admin: self.admin,
+            treasury: self.treasury,
+            platform_fee_percentage: self.platform_fee_percentage,
+            min_launch_duration: self.min_launch_duration,
+            max_launch_duration: self.max_launch_duration,
+            min_soft_cap: self.min_soft_cap,
+            listing_fee: 0,
+            creator_bond_amount: 0,
+            referral_fee_share: 0,
+            insurance_fee_share: 0,
+            milestone_threshold: 0,
+            refund_window: 0,
+            is_paused: self.is_paused,
+            total_launches: self.total_launches,
+            total_raised: self.total_raised,
+            total_fees_collected: self.total_fees_collected,
+            bump: self.bump,
+            version: PLATFORM_CONFIG_VERSION,
+            // Baseline launches chose their own IDs; the admin advances this past them
+            last_launch_id: 0,
+            stake_mint: Pubkey::default(),
+            staking_tiers: Vec::new(),
+        }
+    }
+}
+
+/// `LaunchConfig` as written before accounts were versioned, with the project metadata
+/// inline and room for the longest metadata
+#[derive(AnchorDeserialize)]
+struct LaunchConfigV0 {
+    creator: Pubkey,
+    token_mint: Pubkey,
+    launch_id: u64,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    total_supply: u64,
+    presale_price: u64,
+    min_contribution: u64,
+    max_contribution: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+    start_time: i64,
+    end_time: i64,
+    total_raised: u64,
+    contributor_count: u32,
+    status: LaunchStatus,
+    vesting_config: VestingConfig,
+    metadata: LaunchMetadata,
+    bump: u8,
+}
+
+impl LaunchConfigV0 {
+    const LEN: usize = 8 + // discriminator
+        32 + 32 + 8 + // creator, token_mint, launch_id
+        4 + 50 + 4 + 10 + 1 + // name, symbol, decimals
+        9 * 8 + // supply, price, contribution limits, caps, sale window, total_raised
+        4 + 1 + // contributor_count, status
+        8 + 8 + 2 + 1 + // vesting_config
+        4 + 500 + 4 + 100 + 4 + 50 + 4 + 100 + 4 + 100 + 4 + 200 + 4 + 200 + // metadata
+        1; // bump
+
+    /// Rebuild the config in the current layout, returning the metadata that moves to
+    /// its own account. Options the baseline program did not have are set to how it
+    /// behaved: SPL Token mint created by the launch, authority kept by the launch PDA,
+    /// unsold tokens never minted and no withdrawals, transfers or milestones.
+    fn into_current(self, finalized: bool) -> (LaunchConfig, LaunchMetadata) {
+        let launch_config = LaunchConfig {
+            creator: self.creator,
+            token_mint: self.token_mint,
+            launch_id: self.launch_id,
+            name: self.name,
+            symbol: self.symbol,
+            decimals: self.decimals,
+            total_supply: self.total_supply,
+            presale_price: self.presale_price,
+            min_contribution: self.min_contribution,
+            max_contribution: self.max_contribution,
+            soft_cap: self.soft_cap,
+            hard_cap: self.hard_cap,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            total_raised: self.total_raised,
+            contributor_count: self.contributor_count,
+            status: self.status,
+            vesting_config: self.vesting_config,
+            creator_bond: 0,
+            referred_volume: 0,
+            referral_pool: 0,
+            uses_milestones: false,
+            governance_config: GovernanceConfig::default(),
+            payout_address: self.creator,
+            proposal_count: 0,
+            // Investor accounts could not be closed, so every contributor still has one
+            open_investor_accounts: self.contributor_count,
+            withdrawal_config: WithdrawalConfig::default(),
+            refunds_swept: false,
+            token_program: anchor_spl::token::ID,
+            token_extensions: TokenExtensionsConfig::default(),
+            uses_existing_mint: false,
+            deposited_tokens: 0,
+            mint_authority_policy: MintAuthorityPolicy::Retain,
+            unsold_token_policy: UnsoldTokenPolicy::Burn,
+            bump: self.bump,
+            version: LAUNCH_CONFIG_VERSION,
+            position_transfer_policy: PositionTransferPolicy::Disabled,
+            position_nfts: false,
+            reserved_allocation: 0,
+            finalized,
+        };
+        (launch_config, self.metadata)
+    }
+}
+
+/// `InvestorAccount` as written before accounts were versioned
+#[derive(AnchorDeserialize)]
+struct InvestorAccountV0 {
+    investor: Pubkey,
+    launch_id: u64,
+    contribution_amount: u64,
+    token_allocation: u64,
+    claimed_amount: u64,
+    last_claim_time: i64,
+    is_refunded: bool,
+    bump: u8,
+}
+
+impl InvestorAccountV0 {
+    const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// Rebuild the account in the current layout
+    fn into_current(self) -> InvestorAccount {
+        InvestorAccount {
+            investor: self.investor,
+            launch_id: self.launch_id,
+            contribution_amount: self.contribution_amount,
+            token_allocation: self.token_allocation,
+            claimed_amount: self.claimed_amount,
+            last_claim_time: self.last_claim_time,
+            is_refunded: self.is_refunded,
+            insurance_claimed: false,
+            milestone_refunded: false,
+            bump: self.bump,
+            version: INVESTOR_ACCOUNT_VERSION,
+            ledger_entries: 0,
+            position_received_at: 0,
+            position_mint: Pubkey::default(),
+            referrer: Pubkey::default(),
+            referred_amount: 0,
+            received_allocation: 0,
+        }
+    }
+}
+
+// Baseline accounts are told apart from current ones by their size
+const _: () = assert!(PlatformConfigV0::LEN != PlatformConfig::LEN);
+const _: () = assert!(LaunchConfigV0::LEN != LaunchConfig::LEN);
+const _: () = assert!(InvestorAccountV0::LEN != InvestorAccount::LEN);
+
+/// Decode an account written before versioning. Any other size means the account was
+/// created or migrated by a versioned program.
+fn decode_v0<T: AnchorDeserialize>(data: &[u8], discriminator: [u8; 8], len: usize) -> Result<T> {
+    if data.len() != len {
+        return Err(LaunchpadError::AccountAlreadyMigrated.into());
+    }
+    if data[..8] != discriminator {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    T::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Resize an account to `len`, topping up rent from `payer` when it grows, and write
+/// `value` over its zeroed data
+fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    len: usize,
+    value: &T,
+) -> Result<()> {
+    if account.data_len() > len {
+        account.realloc(len, false)?;
+    } else {
+        resize_account(account, payer, system_program, len)?;
+    }
+    account.try_borrow_mut_data()?.fill(0);
+    store_account(account, value)
+}
+
+// Migrate Platform Config
+#[derive(Accounts)]
+pub struct MigratePlatformConfig<'info> {
+    /// CHECK: Decoded with the layout it was written in
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump,
+        owner = crate::ID
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+    let account = ctx.accounts.platform_config.to_account_info();
+    let platform_config: PlatformConfigV0 = decode_v0(
+        &account.try_borrow_data()?,
+        PlatformConfig::DISCRIMINATOR,
+        PlatformConfigV0::LEN,
+    )?;
+
+    rewrite_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        PlatformConfig::LEN,
+        &platform_config.into_current(),
+    )?;
+
+    msg!("Platform config migrated to version {}", PLATFORM_CONFIG_VERSION);
+
+    Ok(())
+}
+
+// Initialize Platform Stats
+#[derive(Accounts)]
+pub struct InitializePlatformStats<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = PlatformStats::LEN,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
+    // Carry over what the config counted; counters it never tracked start at zero
+    let platform_stats = PlatformStats::from_config(
+        &ctx.accounts.platform_config,
+        ctx.bumps.platform_stats,
+    );
+    ctx.accounts.platform_stats.set_inner(platform_stats);
+
+    msg!(
+        "Platform stats created from config: {} launches, {} lamports raised",
+        ctx.accounts.platform_stats.launches_created,
+        ctx.accounts.platform_stats.total_raised
+    );
+
+    Ok(())
+}
+
+// Migrate Launch Config
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct MigrateLaunchConfig<'info> {
+    /// CHECK: Decoded with the layout it was written in
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub launch_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Launch metadata PDA, created in the handler from the inline metadata
+    #[account(
+        mut,
+        seeds = [LAUNCH_METADATA_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub launch_metadata: UncheckedAccount<'info>,
+    
+    /// Mint of the launch, checked against the config in the handler
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_launch_config(ctx: Context<MigrateLaunchConfig>, launch_id: u64) -> Result<()> {
+    let account = ctx.accounts.launch_config.to_account_info();
+    let launch_config: LaunchConfigV0 = decode_v0(
+        &account.try_borrow_data()?,
+        LaunchConfig::DISCRIMINATOR,
+        LaunchConfigV0::LEN,
+    )?;
+    if ctx.accounts.token_mint.key() != launch_config.token_mint {
+        return Err(LaunchpadError::InvalidLaunchMint.into());
+    }
+
+    // The baseline program set `Successful` as soon as the hard cap was reached and only
+    // minted the presale tokens when finalizing, so a successful launch with no supply
+    // still has to be finalized
+    let finalized = match launch_config.status {
+        LaunchStatus::Failed => true,
+        LaunchStatus::Successful => ctx.accounts.token_mint.supply > 0,
+        _ => false,
+    };
+    let (launch_config, metadata) = launch_config.into_current(finalized);
+
+    create_launch_metadata(&ctx, launch_id, metadata)?;
+    rewrite_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        LaunchConfig::LEN,
+        &launch_config,
+    )?;
+
+    msg!("Launch {} config migrated to version {}", launch_id, LAUNCH_CONFIG_VERSION);
+
+    Ok(())
+}
+
+/// Create the metadata account of a launch from the metadata its config kept inline
+fn create_launch_metadata(
+    ctx: &Context<MigrateLaunchConfig>,
+    launch_id: u64,
+    metadata: LaunchMetadata,
+) -> Result<()> {
+    let account = ctx.accounts.launch_metadata.to_account_info();
+    let launch_id_bytes = launch_id.to_le_bytes();
+    let bump = ctx.bumps.launch_metadata;
+    create_program_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        &[LAUNCH_METADATA_SEED, launch_id_bytes.as_ref(), &[bump]],
+        LaunchMetadataAccount::space(&metadata),
+    )?;
+
+    let launch_metadata = LaunchMetadataAccount {
+        launch_id,
+        metadata,
+        updates_allowed: false,
+        updated_at: Clock::get()?.unix_timestamp,
+        bump,
+        version: LAUNCH_METADATA_VERSION,
+    };
+    store_account(&account, &launch_metadata)
+}
+
+// Migrate Investor Account
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct MigrateInvestorAccount<'info> {
+    /// CHECK: Decoded with the layout it was written in
+    #[account(
+        mut,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub investor_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Owner of the investor account, only used to derive its address
+    pub investor: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_investor_account(ctx: Context<MigrateInvestorAccount>, launch_id: u64) -> Result<()> {
+    let account = ctx.accounts.investor_account.to_account_info();
+    let investor_account: InvestorAccountV0 = decode_v0(
+        &account.try_borrow_data()?,
+        InvestorAccount::DISCRIMINATOR,
+        InvestorAccountV0::LEN,
+    )?;
+
+    rewrite_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        InvestorAccount::LEN,
+        &investor_account.into_current(),
+    )?;
+
+    msg!(
+        "Investor account of {} for launch {} migrated to version {}",
+        ctx.accounts.investor.key(),
+        launch_id,
+        INVESTOR_ACCOUNT_VERSION
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Byte image of a baseline account: discriminator, the fields in baseline order,
+    /// then zeros up to the allocated size
+    fn baseline_image(discriminator: [u8; 8], fields: impl AnchorSerialize, len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        fields.serialize(&mut data).unwrap();
+        assert!(data.len() <= len);
+        data.resize(len, 0);
+        data
+    }
+
+    fn reencode<T: AccountSerialize + AccountDeserialize>(value: &T, len: usize) -> T {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= len);
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn migrates_baseline_platform_config() {
+        let admin = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let data = baseline_image(
+            PlatformConfig::DISCRIMINATOR,
+            (
+                admin,
+                treasury,
+                250u16,
+                86_400i64,
+                2_592_000i64,
+                1_000_000_000u64,
+                true,
+                3u64,
+                40_000_000_000u64,
+                1_000_000_000u64,
+                254u8,
+            ),
+            PlatformConfigV0::LEN,
+        );
+
+        let v0: PlatformConfigV0 =
+            decode_v0(&data, PlatformConfig::DISCRIMINATOR, PlatformConfigV0::LEN).unwrap();
+        let config = reencode(&v0.into_current(), PlatformConfig::LEN);
+
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.treasury, treasury);
+        assert_eq!(config.platform_fee_percentage, 250);
+        assert_eq!(config.min_launch_duration, 86_400);
+        assert_eq!(config.max_launch_duration, 2_592_000);
+        assert_eq!(config.min_soft_cap, 1_000_000_000);
+        assert!(config.is_paused);
+        assert_eq!(config.total_launches, 3);
+        assert_eq!(config.total_raised, 40_000_000_000);
+        assert_eq!(config.total_fees_collected, 1_000_000_000);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.version, PLATFORM_CONFIG_VERSION);
+        assert_eq!(config.listing_fee, 0);
+        assert_eq!(config.last_launch_id, 0);
+        assert!(config.staking_tiers.is_empty());
+    }
+
+    #[test]
+    fn migrates_baseline_launch_config() {
+        let creator = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+        let data = baseline_image(
+            LaunchConfig::DISCRIMINATOR,
+            (
+                creator,
+                token_mint,
+                7u64,
+                "Kravtrade".to_string(),
+                "KRAV".to_string(),
+                9u8,
+                [1_000_000u64, 100, 10, 1_000, 5_000, 10_000],
+                [1_700_000_000i64, 1_700_086_400],
+                6_000u64,
+                2u32,
+                LaunchStatus::Successful,
+                VestingConfig {
+                    cliff_duration: 3_600,
+                    vesting_duration: 86_400,
+                    initial_unlock_percentage: 2_500,
+                    is_linear: true,
+                },
+                LaunchMetadata {
+                    description: "Token launch".to_string(),
+                    website: "https://kravtrade.io".to_string(),
+                    twitter: "@kravtrade".to_string(),
+                    ..LaunchMetadata::default()
+                },
+                253u8,
+            ),
+            LaunchConfigV0::LEN,
+        );
+
+        let v0: LaunchConfigV0 =
+            decode_v0(&data, LaunchConfig::DISCRIMINATOR, LaunchConfigV0::LEN).unwrap();
+        let (config, metadata) = v0.into_current(true);
+        let config = reencode(&config, LaunchConfig::LEN);
+
+        assert_eq!(config.creator, creator);
+        assert_eq!(config.token_mint, token_mint);
+        assert_eq!(config.launch_id, 7);
+        assert_eq!(config.name, "Kravtrade");
+        assert_eq!(config.symbol, "KRAV");
+        assert_eq!(config.decimals, 9);
+        assert_eq!(config.total_supply, 1_000_000);
+        assert_eq!(config.presale_price, 100);
+        assert_eq!(config.min_contribution, 10);
+        assert_eq!(config.max_contribution, 1_000);
+        assert_eq!(config.soft_cap, 5_000);
+        assert_eq!(config.hard_cap, 10_000);
+        assert_eq!(config.start_time, 1_700_000_000);
+        assert_eq!(config.end_time, 1_700_086_400);
+        assert_eq!(config.total_raised, 6_000);
+        assert_eq!(config.contributor_count, 2);
+        assert!(config.status == LaunchStatus::Successful);
+        assert_eq!(config.vesting_config.cliff_duration, 3_600);
+        assert_eq!(config.vesting_config.vesting_duration, 86_400);
+        assert_eq!(config.vesting_config.initial_unlock_percentage, 2_500);
+        assert_eq!(config.bump, 253);
+        assert_eq!(config.version, LAUNCH_CONFIG_VERSION);
+        assert!(config.finalized);
+        assert_eq!(config.open_investor_accounts, 2);
+        assert_eq!(config.payout_address, creator);
+        assert_eq!(config.token_program, anchor_spl::token::ID);
+        assert!(config.mint_authority_policy == MintAuthorityPolicy::Retain);
+        assert_eq!(metadata.description, "Token launch");
+        assert_eq!(metadata.website, "https://kravtrade.io");
+        assert_eq!(metadata.twitter, "@kravtrade");
+        assert!(metadata.documentation.is_empty());
+    }
+
+    #[test]
+    fn migrates_baseline_investor_account() {
+        let investor = Pubkey::new_unique();
+        let data = baseline_image(
+            InvestorAccount::DISCRIMINATOR,
+            (
+                investor,
+                [7u64, 1_000, 10, 4],
+                1_700_100_000i64,
+                false,
+                252u8,
+            ),
+            InvestorAccountV0::LEN,
+        );
+
+        let v0: InvestorAccountV0 =
+            decode_v0(&data, InvestorAccount::DISCRIMINATOR, InvestorAccountV0::LEN).unwrap();
+        let account = reencode(&v0.into_current(), InvestorAccount::LEN);
+
+        assert_eq!(account.investor, investor);
+        assert_eq!(account.launch_id, 7);
+        assert_eq!(account.contribution_amount, 1_000);
+        assert_eq!(account.token_allocation, 10);
+        assert_eq!(account.claimed_amount, 4);
+        assert_eq!(account.last_claim_time, 1_700_100_000);
+        assert!(!account.is_refunded);
+        assert_eq!(account.bump, 252);
+        assert_eq!(account.version, INVESTOR_ACCOUNT_VERSION);
+        assert_eq!(account.received_allocation, 0);
+        assert!(!account.has_position_nft());
+    }
+
+    #[test]
+    fn rejects_accounts_in_the_current_layout() {
+        let data = vec![0; InvestorAccount::LEN];
+        let result: Result<InvestorAccountV0> =
+            decode_v0(&data, InvestorAccount::DISCRIMINATOR, InvestorAccountV0::LEN);
+        assert_eq!(result.err(), Some(LaunchpadError::AccountAlreadyMigrated.into()));
+    }
+}
@@ -0,0 +1,498 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use crate::state::{
+    LaunchConfig, InvestorAccount, LaunchStatus, Milestone, MilestoneParams,
+    MilestoneSchedule, MilestoneStatus, MilestoneVote, PlatformStats, LedgerEntryKind,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::{authorize_position_holder, record_ledger_entry, transfer_from_treasury};
+
+// Configure Milestones
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ConfigureMilestones<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = MilestoneSchedule::LEN,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_milestones(
+    ctx: Context<ConfigureMilestones>,
+    launch_id: u64,
+    milestones: Vec<MilestoneParams>,
+) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+
+    // The schedule is locked once the launch is reviewed
+    if launch_config.status != LaunchStatus::Pending {
+        return Err(LaunchpadError::LaunchAlreadyFinalized.into());
+    }
+
+    MilestoneSchedule::validate_params(&milestones, launch_config.end_time)?;
+
+    let milestone_schedule = &mut ctx.accounts.milestone_schedule;
+    milestone_schedule.launch_id = launch_id;
+    milestone_schedule.milestones = milestones
+        .iter()
+        .map(|params| Milestone {
+            unlock_time: params.unlock_time,
+            release_percentage: params.release_percentage,
+            ..Milestone::default()
+        })
+        .collect();
+    milestone_schedule.escrowed_amount = 0;
+    milestone_schedule.released_amount = 0;
+    milestone_schedule.refund_pool = 0;
+    milestone_schedule.refunds_enabled = false;
+    milestone_schedule.bump = ctx.bumps.milestone_schedule;
+
+    launch_config.uses_milestones = true;
+
+    msg!(
+        "Milestone schedule configured for launch {}: {} tranches",
+        launch_id,
+        milestone_schedule.milestones.len()
+    );
+
+    Ok(())
+}
+
+// Start Milestone Vote
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct StartMilestoneVote<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn start_milestone_vote(
+    ctx: Context<StartMilestoneVote>,
+    launch_id: u64,
+    milestone_index: u8,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let milestone_schedule = &mut ctx.accounts.milestone_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+    let index = milestone_index as usize;
+
+    if launch_config.status != LaunchStatus::Successful || milestone_schedule.refunds_enabled {
+        return Err(LaunchpadError::MilestoneVotingClosed.into());
+    }
+
+    if index >= milestone_schedule.milestones.len() {
+        return Err(LaunchpadError::InvalidMilestoneSchedule.into());
+    }
+
+    // Tranches are released in order
+    if !milestone_schedule.previous_released(index) {
+        return Err(LaunchpadError::MilestoneLocked.into());
+    }
+
+    let milestone = &mut milestone_schedule.milestones[index];
+    if milestone.status != MilestoneStatus::Locked {
+        return Err(LaunchpadError::MilestoneVotingClosed.into());
+    }
+    if current_time < milestone.unlock_time {
+        return Err(LaunchpadError::MilestoneLocked.into());
+    }
+
+    milestone.status = MilestoneStatus::Voting;
+    milestone.voting_ends_at = current_time
+        .checked_add(MILESTONE_VOTING_PERIOD)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    milestone.votes_for = 0;
+    milestone.votes_against = 0;
+    milestone.vote_round = milestone.vote_round
+        .checked_add(1)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    msg!(
+        "Milestone {} vote opened for launch {} until {}",
+        milestone_index,
+        launch_id,
+        milestone.voting_ends_at
+    );
+
+    Ok(())
+}
+
+// Vote Milestone
+#[derive(Accounts)]
+#[instruction(launch_id: u64, milestone_index: u8)]
+pub struct VoteMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+
+    #[account(
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// One vote per position and round, whoever holds it
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = MilestoneVote::LEN,
+        seeds = [
+            MILESTONE_VOTE_SEED,
+            launch_id.to_le_bytes().as_ref(),
+            &[milestone_index],
+            investor_account.investor.as_ref()
+        ],
+        bump
+    )]
+    pub milestone_vote: Account<'info, MilestoneVote>,
+
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_milestone(
+    ctx: Context<VoteMilestone>,
+    launch_id: u64,
+    milestone_index: u8,
+    approve: bool,
+) -> Result<()> {
+    let investor_account = &ctx.accounts.investor_account;
+    let milestone_schedule = &mut ctx.accounts.milestone_schedule;
+    let milestone_vote = &mut ctx.accounts.milestone_vote;
+    let current_time = Clock::get()?.unix_timestamp;
+    let index = milestone_index as usize;
+
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    if index >= milestone_schedule.milestones.len() {
+        return Err(LaunchpadError::InvalidMilestoneSchedule.into());
+    }
+
+    let milestone = &mut milestone_schedule.milestones[index];
+    if milestone.status != MilestoneStatus::Voting || current_time > milestone.voting_ends_at {
+        return Err(LaunchpadError::MilestoneVotingClosed.into());
+    }
+
+    // One vote per investor per round
+    if milestone_vote.vote_round == milestone.vote_round {
+        return Err(LaunchpadError::AlreadyVoted.into());
+    }
+
+    // Voting weight is the investor's contribution
+    if investor_account.contribution_amount == 0 || investor_account.is_refunded {
+        return Err(LaunchpadError::NoVotingPower.into());
+    }
+
+    // Positions moved in after the round opened may already have voted from another wallet
+    let round_opened_at = milestone.voting_ends_at.saturating_sub(MILESTONE_VOTING_PERIOD);
+    let weight = investor_account.vote_weight_since(investor_account.contribution_amount, round_opened_at)?;
+    if weight == 0 {
+        return Err(LaunchpadError::PositionReceivedDuringVote.into());
+    }
+
+    if approve {
+        milestone.votes_for = milestone.votes_for
+            .checked_add(weight)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    } else {
+        milestone.votes_against = milestone.votes_against
+            .checked_add(weight)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+
+    milestone_vote.investor = investor_account.investor;
+    milestone_vote.launch_id = launch_id;
+    milestone_vote.milestone_index = milestone_index;
+    milestone_vote.vote_round = milestone.vote_round;
+    milestone_vote.weight = weight;
+    milestone_vote.approve = approve;
+    milestone_vote.bump = ctx.bumps.milestone_vote;
+
+    msg!(
+        "Milestone {} vote for launch {}: {} by {} with weight {}",
+        milestone_index,
+        launch_id,
+        if approve { "approve" } else { "reject" },
+        ctx.accounts.investor.key(),
+        weight
+    );
+
+    Ok(())
+}
+
+// Release Milestone
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ReleaseMilestone<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+
+    /// CHECK: Treasury account holding the escrowed funds
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+
+    /// CHECK: Address receiving the creator proceeds
+    #[account(
+        mut,
+        constraint = payout_account.key() == launch_config.payout_address @ LaunchpadError::Unauthorized
+    )]
+    pub payout_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn release_milestone(
+    ctx: Context<ReleaseMilestone>,
+    launch_id: u64,
+    milestone_index: u8,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let milestone_schedule = &mut ctx.accounts.milestone_schedule;
+    let current_time = Clock::get()?.unix_timestamp;
+    let index = milestone_index as usize;
+
+    // Once refunds are enabled the unreleased escrow belongs to investors
+    if milestone_schedule.refunds_enabled {
+        return Err(LaunchpadError::MilestoneVotingClosed.into());
+    }
+
+    if index >= milestone_schedule.milestones.len() {
+        return Err(LaunchpadError::InvalidMilestoneSchedule.into());
+    }
+
+    let milestone = &milestone_schedule.milestones[index];
+    if milestone.status != MilestoneStatus::Voting {
+        return Err(LaunchpadError::MilestoneVotingClosed.into());
+    }
+    if current_time <= milestone.voting_ends_at {
+        return Err(LaunchpadError::MilestoneVotingInProgress.into());
+    }
+
+    let total_votes = milestone.votes_for
+        .checked_add(milestone.votes_against)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    let quorum = launch_config.total_raised
+        .checked_mul(MILESTONE_QUORUM_BPS as u64)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS_MAX as u64)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    let approved = milestone.votes_for > milestone.votes_against;
+
+    if total_votes < quorum {
+        // No quorum - the creator may open a new vote
+        milestone_schedule.milestones[index].status = MilestoneStatus::Locked;
+
+        msg!(
+            "Milestone {} vote for launch {} did not reach quorum",
+            milestone_index,
+            launch_id
+        );
+    } else if approved {
+        let release_amount = milestone_schedule.calculate_release_amount(index)?;
+
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            &ctx.accounts.payout_account,
+            launch_id,
+            ctx.bumps.treasury_account,
+            release_amount,
+        )?;
+
+        milestone_schedule.released_amount = milestone_schedule.released_amount
+            .checked_add(release_amount)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        milestone_schedule.milestones[index].status = MilestoneStatus::Released;
+
+        msg!(
+            "Milestone {} released for launch {}: {} lamports",
+            milestone_index,
+            launch_id,
+            release_amount
+        );
+    } else {
+        // Vote failed - freeze the remaining escrow for investor refunds
+        milestone_schedule.milestones[index].status = MilestoneStatus::Rejected;
+        milestone_schedule.enable_refunds();
+
+        msg!(
+            "Milestone {} rejected for launch {}, refundable: {} lamports",
+            milestone_index,
+            launch_id,
+            milestone_schedule.refund_pool
+        );
+    }
+
+    Ok(())
+}
+
+// Claim Milestone Refund
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ClaimMilestoneRefund<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+
+    #[account(
+        mut,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+
+    /// CHECK: Treasury account holding the escrowed funds
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_milestone_refund(ctx: Context<ClaimMilestoneRefund>, launch_id: u64) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let milestone_schedule = &ctx.accounts.milestone_schedule;
+    let investor_account = &mut ctx.accounts.investor_account;
+
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    if !milestone_schedule.refunds_enabled {
+        return Err(LaunchpadError::RefundNotAvailable.into());
+    }
+
+    if investor_account.milestone_refunded {
+        return Err(LaunchpadError::AlreadyRefunded.into());
+    }
+
+    let refund_amount = milestone_schedule.calculate_refund(
+        investor_account.contribution_amount,
+        launch_config.total_raised,
+    )?;
+
+    if refund_amount == 0 {
+        return Err(LaunchpadError::RefundNotAvailable.into());
+    }
+
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.investor.to_account_info(),
+        launch_id,
+        ctx.bumps.treasury_account,
+        refund_amount,
+    )?;
+
+    investor_account.milestone_refunded = true;
+    ctx.accounts.platform_stats.record_refund(refund_amount)?;
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::MilestoneRefund,
+        refund_amount,
+        launch_config.presale_price,
+    )?;
+
+    msg!(
+        "Milestone refund processed: {} lamports to {} for launch {}",
+        refund_amount,
+        ctx.accounts.investor.key(),
+        launch_id
+    );
+
+    Ok(())
+}
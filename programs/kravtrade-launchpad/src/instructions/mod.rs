@@ -1,15 +1,43 @@
 pub mod initialize_platform;
 pub mod create_launch;
+pub mod update_launch;
+pub mod fund_launch;
 pub mod contribute;
+pub mod withdraw_contribution;
 pub mod claim_tokens;
+pub mod distribute_tokens;
 pub mod claim_refund;
+pub mod process_refunds;
 pub mod finalize_launch;
 pub mod admin;
+pub mod referral;
+pub mod insurance;
+pub mod milestones;
+pub mod governance;
+pub mod close_accounts;
+pub mod migrate;
+pub mod launch_metadata;
+pub mod transfer_position;
+pub mod staking;
 
 pub use initialize_platform::*;
 pub use create_launch::*;
+pub use update_launch::*;
+pub use fund_launch::*;
 pub use contribute::*;
+pub use withdraw_contribution::*;
 pub use claim_tokens::*;
+pub use distribute_tokens::*;
 pub use claim_refund::*;
+pub use process_refunds::*;
 pub use finalize_launch::*;
-pub use admin::*;
\ No newline at end of file
+pub use admin::*;
+pub use referral::*;
+pub use insurance::*;
+pub use milestones::*;
+pub use governance::*;
+pub use close_accounts::*;
+pub use migrate::*;
+pub use launch_metadata::*;
+pub use transfer_position::*;
+pub use staking::*;
\ No newline at end of file
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::state::{LaunchConfig, InvestorAccount, LaunchStatus, PlatformStats, LedgerEntryKind};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::RefundProcessed;
+use crate::utils::{
+    authorize_crank_recipient, optional_remaining_account, record_ledger_entry, transfer_from_treasury,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ProcessRefunds<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    /// CHECK: Treasury account holding the contributions
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Pays for any contribution ledger pages that need creating
+    #[account(mut)]
+    pub processor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund a batch of investors of a failed or cancelled launch.
+/// `remaining_accounts` holds `(investor_account, recipient, contribution_ledger, position_token_account)`
+/// tuples. The recipient is the wallet that contributed, or the NFT holder for NFT positions. The
+/// program ID stands in for a ledger page or token account the investor does not have.
+pub fn process_refunds<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessRefunds<'info>>,
+    launch_id: u64,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if launch failed or was cancelled
+    if launch_config.status != LaunchStatus::Failed && launch_config.status != LaunchStatus::Cancelled {
+        return Err(LaunchpadError::RefundNotAvailable.into());
+    }
+
+    // Swept refunds are claimed individually from the holding account
+    if launch_config.refunds_swept {
+        return Err(LaunchpadError::RefundsSwept.into());
+    }
+
+    if ctx.remaining_accounts.is_empty() || ctx.remaining_accounts.len() % 4 != 0 {
+        return Err(LaunchpadError::InvalidRemainingAccounts.into());
+    }
+
+    let mut refunded_count: u32 = 0;
+    let mut refunded_total: u64 = 0;
+
+    for accounts in ctx.remaining_accounts.chunks_exact(4) {
+        let investor_account_info = &accounts[0];
+        let recipient_info = &accounts[1];
+        let ledger_info = optional_remaining_account(&accounts[2]);
+        let position_token_info = optional_remaining_account(&accounts[3]);
+
+        // Ownership and discriminator are checked on deserialization
+        let mut investor_account: Account<InvestorAccount> = Account::try_from(investor_account_info)?;
+
+        if investor_account.launch_id != launch_id {
+            return Err(LaunchpadError::InvalidRemainingAccounts.into());
+        }
+
+        // NFT positions are refunded to whoever holds the NFT
+        authorize_crank_recipient(&investor_account, &recipient_info.key(), position_token_info)?;
+
+        // Skip positions that are already settled so batches can be retried
+        if !investor_account.is_eligible_for_refund() {
+            continue;
+        }
+
+        let refund_amount = investor_account.contribution_amount;
+
+        transfer_from_treasury(
+            &ctx.accounts.system_program,
+            &ctx.accounts.treasury_account,
+            recipient_info,
+            launch_id,
+            ctx.bumps.treasury_account,
+            refund_amount,
+        )?;
+
+        investor_account.mark_refunded();
+        record_ledger_entry(
+            ledger_info,
+            &mut investor_account,
+            &ctx.accounts.processor.to_account_info(),
+            &ctx.accounts.system_program,
+            LedgerEntryKind::Refund,
+            refund_amount,
+            launch_config.presale_price,
+        )?;
+        investor_account.exit(&crate::ID)?;
+
+        emit_cpi!(RefundProcessed {
+            launch_id,
+            investor: recipient_info.key(),
+            amount: refund_amount,
+            processor: ctx.accounts.processor.key(),
+            timestamp: current_time,
+        });
+
+        refunded_count = refunded_count
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        refunded_total = refunded_total
+            .checked_add(refund_amount)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+
+    ctx.accounts.platform_stats.record_refund(refunded_total)?;
+
+    msg!(
+        "Batch refund processed for launch {}: {} investors, {} lamports",
+        launch_id,
+        refunded_count,
+        refunded_total
+    );
+
+    Ok(())
+}
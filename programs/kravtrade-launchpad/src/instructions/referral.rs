@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::{LaunchConfig, LaunchStatus, Referrer};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::transfer_from_treasury;
+
+// Register Referrer
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = Referrer::LEN,
+        seeds = [REFERRER_SEED, launch_id.to_le_bytes().as_ref(), referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_referrer(ctx: Context<RegisterReferrer>, launch_id: u64) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+
+    // Referrers can only join launches that are still raising
+    if launch_config.status != LaunchStatus::Pending && launch_config.status != LaunchStatus::Active {
+        return Err(LaunchpadError::PresaleNotActive.into());
+    }
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.referrer = ctx.accounts.referrer.key();
+    referrer_account.launch_id = launch_id;
+    referrer_account.referred_volume = 0;
+    referrer_account.referral_count = 0;
+    referrer_account.claimed_amount = 0;
+    referrer_account.rewards_claimed = false;
+    referrer_account.bump = ctx.bumps.referrer_account;
+
+    msg!(
+        "Referrer {} registered for launch {}",
+        ctx.accounts.referrer.key(),
+        launch_id
+    );
+
+    Ok(())
+}
+
+// Claim Referral Rewards
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, launch_id.to_le_bytes().as_ref(), referrer.key().as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    /// CHECK: Treasury account holding the referral pool
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>, launch_id: u64) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let referrer_account = &mut ctx.accounts.referrer_account;
+
+    // Rewards are only paid out of successful launches
+    if launch_config.status != LaunchStatus::Successful {
+        return Err(LaunchpadError::LaunchNotApproved.into());
+    }
+
+    if referrer_account.rewards_claimed {
+        return Err(LaunchpadError::AlreadyClaimed.into());
+    }
+
+    let reward = referrer_account.calculate_reward(
+        launch_config.referral_pool,
+        launch_config.referred_volume,
+    )?;
+
+    if reward == 0 {
+        return Err(LaunchpadError::NoReferralRewards.into());
+    }
+
+    // Pay the reward out of the launch treasury
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.referrer.to_account_info(),
+        launch_id,
+        ctx.bumps.treasury_account,
+        reward,
+    )?;
+
+    referrer_account.claimed_amount = reward;
+    referrer_account.rewards_claimed = true;
+
+    // Draw the pool down with the volume it paid for, so the rest of the pool stays
+    // pro-rata to the unclaimed volume and reaches zero once every referrer has claimed
+    launch_config.referral_pool = launch_config.referral_pool.saturating_sub(reward);
+    launch_config.referred_volume = launch_config.referred_volume
+        .saturating_sub(referrer_account.referred_volume);
+
+    msg!(
+        "Referral rewards claimed: {} lamports by {} for launch {}",
+        reward,
+        ctx.accounts.referrer.key(),
+        launch_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,353 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{
+    InvestorAccount, LaunchConfig, LaunchStatus, PlatformConfig, StakeAccount,
+    StakingTier,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::{AllocationReserved, TokensStaked, TokensUnstaked};
+use crate::utils::load_account;
+
+// Configure Staking
+#[derive(Accounts)]
+pub struct ConfigureStaking<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ LaunchpadError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mint::token_program = token_program)]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = stake_mint,
+        associated_token::authority = platform_config,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_staking(ctx: Context<ConfigureStaking>, tiers: Vec<StakingTier>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let stake_mint = ctx.accounts.stake_mint.key();
+
+    // Stakes are held in the vault of the first mint configured
+    if platform_config.is_staking_configured() && platform_config.stake_mint != stake_mint {
+        return Err(LaunchpadError::StakeMintLocked.into());
+    }
+
+    StakingTier::validate_tiers(&tiers)?;
+
+    platform_config.stake_mint = stake_mint;
+    platform_config.staking_tiers = tiers;
+
+    msg!(
+        "Staking configured with mint {} and {} tiers",
+        stake_mint,
+        platform_config.staking_tiers.len()
+    );
+
+    Ok(())
+}
+
+// Stake
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.is_staking_configured() @ LaunchpadError::StakingNotConfigured
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = StakeAccount::LEN,
+        seeds = [STAKE_SEED, staker.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(address = platform_config.stake_mint)]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = platform_config,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = staker,
+        token::token_program = token_program,
+    )]
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn stake(ctx: Context<Stake>, amount: u64, lock_duration: i64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(LaunchpadError::InsufficientFunds.into());
+    }
+    if !(0..=MAX_STAKE_LOCK_DURATION).contains(&lock_duration) {
+        return Err(LaunchpadError::InvalidStakeLock.into());
+    }
+
+    let stake_account = &mut ctx.accounts.stake_account;
+    if stake_account.owner == Pubkey::default() {
+        stake_account.owner = ctx.accounts.staker.key();
+        stake_account.amount = 0;
+        stake_account.lock_duration = 0;
+        stake_account.locked_until = 0;
+        stake_account.reserved_launch_id = 0;
+        stake_account.reserved_allocation = 0;
+        stake_account.reservation_expires_at = 0;
+        stake_account.bump = ctx.bumps.stake_account;
+        stake_account.version = STAKE_ACCOUNT_VERSION;
+    }
+
+    // Credit what the vault received, net of any Token-2022 transfer fee
+    let vault_balance = ctx.accounts.stake_vault.amount;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.staker_token_account.to_account_info(),
+                mint: ctx.accounts.stake_mint.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.staker.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.stake_mint.decimals,
+    )?;
+    ctx.accounts.stake_vault.reload()?;
+    let received = ctx.accounts.stake_vault.amount.saturating_sub(vault_balance);
+
+    stake_account.add_stake(received, lock_duration, current_time)?;
+
+    emit_cpi!(TokensStaked {
+        owner: stake_account.owner,
+        amount: received,
+        total_staked: stake_account.amount,
+        locked_until: stake_account.locked_until,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Unstake
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, staker.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(address = platform_config.stake_mint)]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = platform_config,
+        associated_token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = staker,
+        token::token_program = token_program,
+    )]
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub staker: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    let stake_account = &mut ctx.accounts.stake_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if stake_account.is_locked(current_time) {
+        return Err(LaunchpadError::StakeLocked.into());
+    }
+    if amount == 0 || amount > stake_account.amount {
+        return Err(LaunchpadError::InsufficientFunds.into());
+    }
+
+    let seeds = &[PLATFORM_SEED, CONFIG_SEED, &[ctx.accounts.platform_config.bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                mint: ctx.accounts.stake_mint.to_account_info(),
+                to: ctx.accounts.staker_token_account.to_account_info(),
+                authority: ctx.accounts.platform_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+        ctx.accounts.stake_mint.decimals,
+    )?;
+
+    // The lock period only counts toward tiers while the stake is locked again
+    stake_account.amount -= amount;
+    stake_account.lock_duration = 0;
+
+    emit_cpi!(TokensUnstaked {
+        owner: stake_account.owner,
+        amount,
+        total_staked: stake_account.amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Reserve Allocation
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct ReserveAllocation<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    /// CHECK: Investor account PDA, read in the handler if the investor has contributed
+    #[account(
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub investor_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, investor.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub investor: Signer<'info>,
+}
+
+pub fn reserve_allocation(ctx: Context<ReserveAllocation>, _launch_id: u64) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if !ctx.accounts.platform_config.is_operational() {
+        return Err(LaunchpadError::PlatformPaused.into());
+    }
+    if !matches!(launch_config.status, LaunchStatus::Pending | LaunchStatus::Active) {
+        return Err(LaunchpadError::PresaleNotActive.into());
+    }
+    if current_time >= launch_config.reservation_deadline() {
+        return Err(LaunchpadError::ReservationPeriodEnded.into());
+    }
+
+    // The stake backing the reservation must outlast the sale
+    if stake_account.locked_until < launch_config.end_time {
+        return Err(LaunchpadError::StakeLockTooShort.into());
+    }
+    let guaranteed = stake_account
+        .tier(&ctx.accounts.platform_config.staking_tiers, current_time)
+        .map_or(0, |tier| tier.guaranteed_allocation);
+
+    // A stake backs one reservation at a time; one in another launch must lapse first
+    let reserved = stake_account.reservation(launch_config.launch_id, current_time);
+    if stake_account.reserved_launch_id != launch_config.launch_id
+        && stake_account.reservation(stake_account.reserved_launch_id, current_time) > 0
+    {
+        return Err(LaunchpadError::ReservationOutstanding.into());
+    }
+
+    // Contributions already made count toward the guarantee
+    let investor_info = ctx.accounts.investor_account.to_account_info();
+    let contributed = if investor_info.data_is_empty() {
+        0
+    } else {
+        load_account::<InvestorAccount>(&investor_info)?.contribution_amount
+    };
+
+    // Reserve what is left of the guarantee after contributions and earlier reservations
+    let amount = guaranteed
+        .saturating_sub(contributed)
+        .saturating_sub(reserved);
+    if amount == 0 {
+        return Err(LaunchpadError::NoAllocationToReserve.into());
+    }
+    if amount > launch_config.unreserved_capacity(current_time) {
+        return Err(LaunchpadError::HardCapExceeded.into());
+    }
+
+    stake_account.reserved_launch_id = launch_config.launch_id;
+    stake_account.reserved_allocation = reserved
+        .checked_add(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    stake_account.reservation_expires_at = launch_config.reservation_deadline();
+    launch_config.reserved_allocation = launch_config.reserved_allocation
+        .checked_add(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    emit_cpi!(AllocationReserved {
+        launch_id: launch_config.launch_id,
+        investor: stake_account.owner,
+        amount,
+        total_reserved: launch_config.reserved_allocation,
+        expires_at: stake_account.reservation_expires_at,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
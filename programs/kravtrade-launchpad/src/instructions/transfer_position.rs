@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    LaunchConfig, InvestorAccount, PlatformConfig, PlatformStats, LaunchStatus, LedgerEntryKind, StakeAccount,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::PositionTransferred;
+use crate::utils::record_ledger_entry;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct TransferPosition<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(
+        mut,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor.key().as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = InvestorAccount::LEN,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_account: Account<'info, InvestorAccount>,
+    
+    /// CHECK: Wallet receiving the position; only used to derive its investor account
+    #[account(constraint = recipient.key() != investor.key() @ LaunchpadError::InvalidPositionRecipient)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Optional stake of the recipient, raising its cap during the sale to its staking tier
+    #[account(
+        seeds = [STAKE_SEED, recipient.key().as_ref()],
+        bump = recipient_stake_account.bump
+    )]
+    pub recipient_stake_account: Option<Account<'info, StakeAccount>>,
+    
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Current page of the recipient's contribution ledger, checked and created in the handler.
+    /// Optional until the recipient starts a ledger, then required.
+    #[account(mut)]
+    pub recipient_ledger: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub investor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_position(
+    ctx: Context<TransferPosition>,
+    launch_id: u64,
+    amount: u64,
+) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let investor_account = &mut ctx.accounts.investor_account;
+    let recipient_account = &mut ctx.accounts.recipient_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // NFT positions move with the NFT instead
+    if launch_config.position_nfts
+        || !launch_config.position_transfer_policy.allows(&launch_config.status, launch_config.finalized)
+    {
+        return Err(LaunchpadError::PositionTransferNotAllowed.into());
+    }
+
+    // Refunded positions have nothing left to move
+    if investor_account.is_refunded
+        || investor_account.milestone_refunded
+        || investor_account.insurance_claimed
+        || recipient_account.is_refunded
+        || recipient_account.milestone_refunded
+        || recipient_account.insurance_claimed
+    {
+        return Err(LaunchpadError::PositionNotTransferable.into());
+    }
+
+    if investor_account.token_allocation > 0
+        && investor_account.claimed_amount >= investor_account.token_allocation
+    {
+        return Err(LaunchpadError::AlreadyClaimed.into());
+    }
+
+    // Set up the recipient's account on first use
+    let is_new_recipient = recipient_account.investor == Pubkey::default();
+    if is_new_recipient {
+        recipient_account.initialize(
+            ctx.accounts.recipient.key(),
+            launch_id,
+            ctx.bumps.recipient_account,
+        );
+        launch_config.open_investor_accounts = launch_config.open_investor_accounts
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+        ctx.accounts.platform_stats.record_contributor()?;
+    }
+
+    let recipient_had_contribution = recipient_account.contribution_amount > 0;
+    let (contribution_moved, claimed_moved) =
+        investor_account.transfer_share(recipient_account, amount, current_time)?;
+
+    // The recipient is held to the same cap as a direct contributor during the sale,
+    // including the staking tier it would contribute with
+    if launch_config.status == LaunchStatus::Active {
+        let max_contribution = ctx.accounts.recipient_stake_account
+            .as_ref()
+            .and_then(|stake_account| {
+                stake_account.tier(&ctx.accounts.platform_config.staking_tiers, current_time)
+            })
+            .map_or(launch_config.max_contribution, |tier| tier.max_contribution(launch_config.max_contribution));
+        if recipient_account.contribution_amount > max_contribution {
+            return Err(LaunchpadError::ContributionTooHigh.into());
+        }
+    }
+
+    if !recipient_had_contribution && recipient_account.contribution_amount > 0 {
+        launch_config.contributor_count = launch_config.contributor_count
+            .checked_add(1)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+    if investor_account.contribution_amount == 0 {
+        launch_config.contributor_count = launch_config.contributor_count.saturating_sub(1);
+    }
+
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::TransferOut,
+        amount,
+        launch_config.presale_price,
+    )?;
+
+    let recipient_ledger = ctx.accounts.recipient_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        recipient_ledger.as_ref(),
+        recipient_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::TransferIn,
+        amount,
+        launch_config.presale_price,
+    )?;
+
+    emit_cpi!(PositionTransferred {
+        launch_id,
+        from: ctx.accounts.investor.key(),
+        to: ctx.accounts.recipient.key(),
+        token_amount: amount,
+        contribution_amount: contribution_moved,
+        claimed_amount: claimed_moved,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    LaunchConfig, PlatformConfig, LaunchStatus, VestingConfig, LaunchMetadata,
+    LaunchMetadataAccount, MilestoneSchedule,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::LaunchUpdated;
+use super::create_launch::validate_launch_parameters;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    launch_id: u64,
+    presale_price: u64,
+    min_contribution: u64,
+    max_contribution: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+    start_time: i64,
+    end_time: i64,
+    vesting_config: VestingConfig,
+    metadata: LaunchMetadata,
+)]
+pub struct UpdateLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump,
+        constraint = launch_config.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCH_METADATA_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_metadata.bump,
+        realloc = LaunchMetadataAccount::space(&metadata),
+        realloc::payer = creator,
+        realloc::zero = false
+    )]
+    pub launch_metadata: Account<'info, LaunchMetadataAccount>,
+
+    /// Milestone schedule validated against the old sale window; closed so it is configured again
+    #[account(
+        mut,
+        close = creator,
+        seeds = [MILESTONE_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = milestone_schedule.bump
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_launch(
+    ctx: Context<UpdateLaunch>,
+    launch_id: u64,
+    presale_price: u64,
+    min_contribution: u64,
+    max_contribution: u64,
+    soft_cap: u64,
+    hard_cap: u64,
+    start_time: i64,
+    end_time: i64,
+    vesting_config: VestingConfig,
+    metadata: LaunchMetadata,
+) -> Result<()> {
+    let launch_config = &ctx.accounts.launch_config;
+
+    // Only launches awaiting review can be edited
+    if launch_config.status != LaunchStatus::Pending {
+        return Err(LaunchpadError::LaunchAlreadyFinalized.into());
+    }
+
+    if !ctx.accounts.platform_config.is_operational() {
+        return Err(LaunchpadError::PlatformPaused.into());
+    }
+
+    // The token itself is fixed at creation; everything else is checked as if new
+    validate_launch_parameters(
+        &launch_config.name,
+        &launch_config.symbol,
+        launch_config.decimals,
+        launch_config.total_supply,
+        presale_price,
+        min_contribution,
+        max_contribution,
+        soft_cap,
+        hard_cap,
+        start_time,
+        end_time,
+        &vesting_config,
+        &launch_config.governance_config,
+        &launch_config.withdrawal_config,
+        &metadata,
+        &ctx.accounts.platform_config,
+    )?;
+
+    // A configured schedule must be closed here, since it was checked against the old end time
+    let milestones_reset = launch_config.uses_milestones;
+    if milestones_reset && ctx.accounts.milestone_schedule.is_none() {
+        return Err(LaunchpadError::MilestoneScheduleMissing.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Any admin unlock granted for the old metadata no longer applies
+    let launch_metadata = &mut ctx.accounts.launch_metadata;
+    launch_metadata.metadata = metadata;
+    launch_metadata.updates_allowed = false;
+    launch_metadata.updated_at = current_time;
+
+    let launch_config = &mut ctx.accounts.launch_config;
+    launch_config.presale_price = presale_price;
+    launch_config.min_contribution = min_contribution;
+    launch_config.max_contribution = max_contribution;
+    launch_config.soft_cap = soft_cap;
+    launch_config.hard_cap = hard_cap;
+    launch_config.start_time = start_time;
+    launch_config.end_time = end_time;
+    launch_config.vesting_config = vesting_config;
+    launch_config.uses_milestones = false;
+
+    emit_cpi!(LaunchUpdated {
+        launch_id,
+        creator: launch_config.creator,
+        presale_price,
+        soft_cap,
+        hard_cap,
+        start_time,
+        end_time,
+        milestones_reset,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use crate::state::{LaunchConfig, InvestorAccount, PlatformConfig, PlatformStats, LaunchStatus, LedgerEntryKind, Referrer};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::utils::{authorize_position_holder, record_ledger_entry, transfer_from_treasury};
+
+#[derive(Accounts)]
+#[instruction(launch_id: u64)]
+pub struct WithdrawContribution<'info> {
+    #[account(
+        mut,
+        seeds = [LAUNCH_SEED, launch_id.to_le_bytes().as_ref()],
+        bump = launch_config.bump
+    )]
+    pub launch_config: Account<'info, LaunchConfig>,
+    
+    #[account(
+        mut,
+        seeds = [INVESTOR_SEED, launch_id.to_le_bytes().as_ref(), investor_account.investor.as_ref()],
+        bump = investor_account.bump
+    )]
+    pub investor_account: Account<'info, InvestorAccount>,
+    
+    #[account(
+        seeds = [PLATFORM_SEED, CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Treasury account holding the contributions
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, launch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub treasury_account: AccountInfo<'info>,
+    
+    /// CHECK: Platform treasury or creator payout address, validated in the handler
+    #[account(mut)]
+    pub penalty_recipient: AccountInfo<'info>,
+    
+    /// Referrer the position's contributions are attributed to, required while it has any
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED, launch_id.to_le_bytes().as_ref(), investor_account.referrer.as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Option<Account<'info, Referrer>>,
+    
+    /// CHECK: Current page of the investor's contribution ledger, checked and created in the handler.
+    /// Optional until the investor starts a ledger, then required.
+    #[account(mut)]
+    pub contribution_ledger: Option<UncheckedAccount<'info>>,
+    
+    /// Holder's position NFT token account, required for positions represented by an NFT
+    pub position_token_account: Option<Account<'info, token::TokenAccount>>,
+    
+    #[account(mut)]
+    pub investor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_contribution(
+    ctx: Context<WithdrawContribution>,
+    launch_id: u64,
+    amount: u64,
+) -> Result<()> {
+    let launch_config = &mut ctx.accounts.launch_config;
+    let investor_account = &mut ctx.accounts.investor_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Positions represented by an NFT are controlled by its current holder
+    authorize_position_holder(
+        investor_account,
+        &ctx.accounts.investor.key(),
+        ctx.accounts.position_token_account.as_ref(),
+    )?;
+
+    // Withdrawals are only possible while the sale is live
+    if launch_config.status != LaunchStatus::Active {
+        return Err(LaunchpadError::PresaleNotActive.into());
+    }
+
+    let withdrawal_config = launch_config.withdrawal_config.clone();
+    if !withdrawal_config.is_withdrawal_open(current_time, launch_config.end_time) {
+        return Err(LaunchpadError::WithdrawalNotAllowed.into());
+    }
+
+    if amount == 0 || amount > investor_account.contribution_amount {
+        return Err(LaunchpadError::InsufficientFunds.into());
+    }
+
+    // A partial withdrawal must leave at least the minimum contribution
+    let remaining_contribution = investor_account.contribution_amount - amount;
+    if remaining_contribution > 0 && remaining_contribution < launch_config.min_contribution {
+        return Err(LaunchpadError::ContributionTooLow.into());
+    }
+
+    let expected_recipient = if withdrawal_config.penalty_to_creator {
+        launch_config.payout_address
+    } else {
+        ctx.accounts.platform_config.treasury
+    };
+    if ctx.accounts.penalty_recipient.key() != expected_recipient {
+        return Err(LaunchpadError::Unauthorized.into());
+    }
+
+    let penalty = withdrawal_config.calculate_penalty(amount)?;
+    let refund_amount = amount.saturating_sub(penalty);
+
+    // Return the contribution less the penalty
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.investor.to_account_info(),
+        launch_id,
+        ctx.bumps.treasury_account,
+        refund_amount,
+    )?;
+
+    transfer_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury_account,
+        &ctx.accounts.penalty_recipient,
+        launch_id,
+        ctx.bumps.treasury_account,
+        penalty,
+    )?;
+
+    if !withdrawal_config.penalty_to_creator {
+        ctx.accounts.platform_stats.record_fees(penalty)?;
+    }
+
+    // Reverse the referral attribution of the withdrawn share
+    let referred_withdrawn = investor_account.withdraw_referred_share(amount)?;
+    if referred_withdrawn > 0 {
+        let referrer_account = ctx.accounts.referrer_account
+            .as_mut()
+            .ok_or(LaunchpadError::ReferrerAccountMissing)?;
+        referrer_account.reverse_referral(referred_withdrawn)?;
+        launch_config.referred_volume = launch_config.referred_volume
+            .checked_sub(referred_withdrawn)
+            .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    }
+
+    // Update investor position
+    investor_account.contribution_amount = remaining_contribution;
+    investor_account.token_allocation = if remaining_contribution == 0 {
+        0
+    } else {
+        investor_account.token_allocation
+            .saturating_sub(launch_config.calculate_token_allocation(amount)?)
+    };
+
+    let ledger_account = ctx.accounts.contribution_ledger.as_ref().map(|ledger| ledger.to_account_info());
+    record_ledger_entry(
+        ledger_account.as_ref(),
+        investor_account,
+        &ctx.accounts.investor.to_account_info(),
+        &ctx.accounts.system_program,
+        LedgerEntryKind::Withdrawal,
+        refund_amount,
+        launch_config.presale_price,
+    )?;
+
+    // Update launch statistics
+    launch_config.total_raised = launch_config.total_raised
+        .checked_sub(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    if remaining_contribution == 0 {
+        launch_config.contributor_count = launch_config.contributor_count.saturating_sub(1);
+    }
+
+    msg!(
+        "Contribution withdrawn: {} lamports by {} for launch {}, penalty: {} lamports",
+        amount,
+        ctx.accounts.investor.key(),
+        launch_id,
+        penalty
+    );
+
+    Ok(())
+}
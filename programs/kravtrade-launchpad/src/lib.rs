@@ -1,16 +1,19 @@
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod constants;
+pub mod utils;
+pub mod events;
 
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::*;
 pub use state::*;
 pub use errors::*;
 
-use instructions::*;
-
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 #[program]
@@ -37,7 +40,6 @@ pub mod kravtrade_launchpad {
     /// Create a new token launch
     pub fn create_launch(
         ctx: Context<CreateLaunch>,
-        launch_id: u64,
         name: String,
         symbol: String,
         decimals: u8,
@@ -50,11 +52,17 @@ pub mod kravtrade_launchpad {
         start_time: i64,
         end_time: i64,
         vesting_config: state::VestingConfig,
+        governance_config: state::GovernanceConfig,
+        withdrawal_config: state::WithdrawalConfig,
         metadata: state::LaunchMetadata,
+        token_extensions: state::TokenExtensionsConfig,
+        mint_authority_policy: state::MintAuthorityPolicy,
+        unsold_token_policy: state::UnsoldTokenPolicy,
+        position_transfer_policy: state::PositionTransferPolicy,
+        position_nfts: bool,
     ) -> Result<()> {
         instructions::create_launch(
             ctx,
-            launch_id,
             name,
             symbol,
             decimals,
@@ -67,25 +75,128 @@ pub mod kravtrade_launchpad {
             start_time,
             end_time,
             vesting_config,
+            governance_config,
+            withdrawal_config,
+            metadata,
+            token_extensions,
+            mint_authority_policy,
+            unsold_token_policy,
+            position_transfer_policy,
+            position_nfts,
+        )
+    }
+
+    /// Creator: Edit prices, caps, times, vesting and metadata of a launch awaiting review
+    pub fn update_launch(
+        ctx: Context<UpdateLaunch>,
+        launch_id: u64,
+        presale_price: u64,
+        min_contribution: u64,
+        max_contribution: u64,
+        soft_cap: u64,
+        hard_cap: u64,
+        start_time: i64,
+        end_time: i64,
+        vesting_config: state::VestingConfig,
+        metadata: state::LaunchMetadata,
+    ) -> Result<()> {
+        instructions::update_launch(
+            ctx,
+            launch_id,
+            presale_price,
+            min_contribution,
+            max_contribution,
+            soft_cap,
+            hard_cap,
+            start_time,
+            end_time,
+            vesting_config,
             metadata,
         )
     }
 
+    /// Deposit the sale allocation of an existing-mint launch into its vault
+    pub fn fund_launch(ctx: Context<FundLaunch>, launch_id: u64, amount: u64) -> Result<()> {
+        instructions::fund_launch(ctx, launch_id, amount)
+    }
+
+    /// Creator: Edit launch metadata while pending, or after approval if the admin allows it
+    pub fn update_launch_metadata(
+        ctx: Context<UpdateLaunchMetadata>,
+        launch_id: u64,
+        metadata: state::LaunchMetadata,
+    ) -> Result<()> {
+        instructions::update_launch_metadata(ctx, launch_id, metadata)
+    }
+
+    /// Admin: Allow or forbid metadata edits of an approved launch
+    pub fn allow_metadata_updates(
+        ctx: Context<AllowMetadataUpdates>,
+        launch_id: u64,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::allow_metadata_updates(ctx, launch_id, allowed)
+    }
+
     /// Contribute to a launch
     pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         instructions::contribute(ctx, amount)
     }
 
+    /// Withdraw part or all of a contribution while the sale is live
+    pub fn withdraw_contribution(
+        ctx: Context<WithdrawContribution>,
+        launch_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_contribution(ctx, launch_id, amount)
+    }
+
+    /// Move all or part of a position to another wallet, if the launch allows it
+    pub fn transfer_position(
+        ctx: Context<TransferPosition>,
+        launch_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_position(ctx, launch_id, amount)
+    }
+
     /// Claim vested tokens
     pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
         instructions::claim_tokens(ctx)
     }
 
+    /// Push claimable tokens to a batch of investors (permissionless)
+    pub fn distribute_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeTokens<'info>>,
+        launch_id: u64,
+    ) -> Result<()> {
+        instructions::distribute_tokens(ctx, launch_id)
+    }
+
     /// Claim refund for failed launch
     pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
         instructions::claim_refund(ctx)
     }
 
+    /// Push refunds to a batch of investors of a failed launch (permissionless)
+    pub fn process_refunds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessRefunds<'info>>,
+        launch_id: u64,
+    ) -> Result<()> {
+        instructions::process_refunds(ctx, launch_id)
+    }
+
+    /// Register as a referrer for a launch
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, launch_id: u64) -> Result<()> {
+        instructions::register_referrer(ctx, launch_id)
+    }
+
+    /// Claim referral rewards from a successful launch
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>, launch_id: u64) -> Result<()> {
+        instructions::claim_referral_rewards(ctx, launch_id)
+    }
+
     /// Finalize a successful launch
     pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
         instructions::finalize_launch(ctx)
@@ -101,6 +212,11 @@ pub mod kravtrade_launchpad {
         instructions::reject_launch(ctx)
     }
 
+    /// Admin: Cancel an approved launch
+    pub fn cancel_launch(ctx: Context<CancelLaunch>, launch_id: u64) -> Result<()> {
+        instructions::cancel_launch(ctx, launch_id)
+    }
+
     /// Admin: Emergency pause a launch
     pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
         instructions::emergency_pause(ctx)
@@ -109,22 +225,158 @@ pub mod kravtrade_launchpad {
     /// Admin: Update platform configuration
     pub fn update_platform_config(
         ctx: Context<UpdatePlatformConfig>,
-        platform_fee_percentage: Option<u16>,
-        min_launch_duration: Option<i64>,
-        max_launch_duration: Option<i64>,
-        min_soft_cap: Option<u64>,
+        update: state::PlatformConfigUpdate,
     ) -> Result<()> {
-        instructions::update_platform_config(
-            ctx,
-            platform_fee_percentage,
-            min_launch_duration,
-            max_launch_duration,
-            min_soft_cap,
-        )
+        instructions::update_platform_config(ctx, update)
+    }
+
+    /// Admin: Initialize the platform insurance fund
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+        instructions::initialize_insurance_fund(ctx)
+    }
+
+    /// Admin: Flag a launch and commit insurance compensation to its investors
+    pub fn flag_launch(
+        ctx: Context<FlagLaunch>,
+        launch_id: u64,
+        compensation_amount: u64,
+    ) -> Result<()> {
+        instructions::flag_launch(ctx, launch_id, compensation_amount)
+    }
+
+    /// Claim insurance compensation for a flagged launch
+    pub fn claim_insurance(ctx: Context<ClaimInsurance>, launch_id: u64) -> Result<()> {
+        instructions::claim_insurance(ctx, launch_id)
+    }
+
+    /// Creator: Configure the milestone schedule for escrowed proceeds
+    pub fn configure_milestones(
+        ctx: Context<ConfigureMilestones>,
+        launch_id: u64,
+        milestones: Vec<MilestoneParams>,
+    ) -> Result<()> {
+        instructions::configure_milestones(ctx, launch_id, milestones)
+    }
+
+    /// Creator: Open an investor vote on releasing a milestone
+    pub fn start_milestone_vote(
+        ctx: Context<StartMilestoneVote>,
+        launch_id: u64,
+        milestone_index: u8,
+    ) -> Result<()> {
+        instructions::start_milestone_vote(ctx, launch_id, milestone_index)
+    }
+
+    /// Vote on a milestone release, weighted by contribution
+    pub fn vote_milestone(
+        ctx: Context<VoteMilestone>,
+        launch_id: u64,
+        milestone_index: u8,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::vote_milestone(ctx, launch_id, milestone_index, approve)
+    }
+
+    /// Resolve a milestone vote and release or freeze the tranche
+    pub fn release_milestone(
+        ctx: Context<ReleaseMilestone>,
+        launch_id: u64,
+        milestone_index: u8,
+    ) -> Result<()> {
+        instructions::release_milestone(ctx, launch_id, milestone_index)
+    }
+
+    /// Claim a refund of unreleased escrow after a rejected milestone
+    pub fn claim_milestone_refund(ctx: Context<ClaimMilestoneRefund>, launch_id: u64) -> Result<()> {
+        instructions::claim_milestone_refund(ctx, launch_id)
+    }
+
+    /// Create an investor governance proposal for a launch
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        launch_id: u64,
+        kind: ProposalKind,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, launch_id, kind)
+    }
+
+    /// Vote on a governance proposal, weighted by token allocation
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        launch_id: u64,
+        proposal_id: u64,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::cast_vote(ctx, launch_id, proposal_id, approve)
+    }
+
+    /// Tally a governance proposal and apply it if it passed
+    pub fn execute_proposal(
+        ctx: Context<ExecuteProposal>,
+        launch_id: u64,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::execute_proposal(ctx, launch_id, proposal_id)
+    }
+
+    /// Close a fully claimed or refunded investor account and reclaim its rent
+    pub fn close_investor_account(ctx: Context<CloseInvestorAccount>, launch_id: u64) -> Result<()> {
+        instructions::close_investor_account(ctx, launch_id)
+    }
+
+    /// Creator: Close a concluded launch and reclaim its vault, treasury and config
+    pub fn close_launch(ctx: Context<CloseLaunch>, launch_id: u64) -> Result<()> {
+        instructions::close_launch(ctx, launch_id)
+    }
+
+    /// Admin: Sweep unclaimed refunds of a failed launch to its refund holding account
+    pub fn sweep_refunds(ctx: Context<SweepRefunds>, launch_id: u64) -> Result<()> {
+        instructions::sweep_refunds(ctx, launch_id)
     }
 
     /// Admin: Collect platform fees
     pub fn collect_fees(ctx: Context<CollectFees>, amount: u64) -> Result<()> {
         instructions::collect_fees(ctx, amount)
     }
+
+    /// Admin: Set the platform stake mint and allocation tiers
+    pub fn configure_staking(ctx: Context<ConfigureStaking>, tiers: Vec<StakingTier>) -> Result<()> {
+        instructions::configure_staking(ctx, tiers)
+    }
+
+    /// Stake platform tokens, locking them for at least `lock_duration` seconds
+    pub fn stake(ctx: Context<Stake>, amount: u64, lock_duration: i64) -> Result<()> {
+        instructions::stake(ctx, amount, lock_duration)
+    }
+
+    /// Withdraw unlocked staked platform tokens
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::unstake(ctx, amount)
+    }
+
+    /// Reserve the guaranteed allocation of the investor's staking tier under the hard cap
+    pub fn reserve_allocation(ctx: Context<ReserveAllocation>, launch_id: u64) -> Result<()> {
+        instructions::reserve_allocation(ctx, launch_id)
+    }
+
+    /// Rebuild a platform config created before accounts were versioned in the current layout
+    pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+        instructions::migrate_platform_config(ctx)
+    }
+
+    /// Create the platform statistics account of a platform initialized before it existed
+    pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
+        instructions::initialize_platform_stats(ctx)
+    }
+
+    /// Rebuild a launch config created before accounts were versioned in the current layout,
+    /// moving its inline metadata into its own account
+    pub fn migrate_launch_config(ctx: Context<MigrateLaunchConfig>, launch_id: u64) -> Result<()> {
+        instructions::migrate_launch_config(ctx, launch_id)
+    }
+
+    /// Rebuild an investor account created before accounts were versioned in the current layout
+    pub fn migrate_investor_account(ctx: Context<MigrateInvestorAccount>, launch_id: u64) -> Result<()> {
+        instructions::migrate_investor_account(ctx, launch_id)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+
+/// Kind of position change recorded in a contribution ledger
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum LedgerEntryKind {
+    /// Lamports contributed to the presale
+    Contribution,
+    /// Lamports withdrawn during the sale, net of the penalty
+    Withdrawal,
+    /// Presale tokens released to the investor
+    Claim,
+    /// Contribution refunded after a failed or cancelled launch
+    Refund,
+    /// Unreleased escrow refunded after a rejected milestone
+    MilestoneRefund,
+    /// Token allocation moved to another wallet
+    TransferOut,
+    /// Token allocation received from another wallet
+    TransferIn,
+}
+
+/// One recorded position change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct LedgerEntry {
+    /// What changed
+    pub kind: LedgerEntryKind,
+    /// Lamports moved, or token base units for claims and transfers
+    pub amount: u64,
+    /// Presale price in lamports per token at the time
+    pub price: u64,
+    /// Unix timestamp of the instruction
+    pub timestamp: i64,
+    /// Slot of the instruction
+    pub slot: u64,
+}
+
+/// One page of an investor's append-only history for a launch. Entry `n` is
+/// always stored on page `n / LEDGER_PAGE_SIZE`.
+#[account]
+#[derive(InitSpace)]
+pub struct ContributionLedger {
+    /// Launch the history belongs to
+    pub launch_id: u64,
+    /// Investor the history belongs to
+    pub investor: Pubkey,
+    /// Page index
+    pub page: u64,
+    /// Entries in the order they were recorded
+    #[max_len(LEDGER_PAGE_SIZE)]
+    pub entries: Vec<LedgerEntry>,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+// Accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(ContributionLedger::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+impl ContributionLedger {
+    /// Space needed for the account, derived from the field types and page size
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Page an entry is stored on
+    pub fn page_for(entry_index: u64) -> u64 {
+        entry_index / LEDGER_PAGE_SIZE
+    }
+
+    /// Address and bump of a page
+    pub fn find_address(launch_id: u64, investor: &Pubkey, page: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                CONTRIBUTION_LEDGER_SEED,
+                launch_id.to_le_bytes().as_ref(),
+                investor.as_ref(),
+                page.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Empty page
+    pub fn new(launch_id: u64, investor: Pubkey, page: u64, bump: u8) -> Self {
+        Self {
+            launch_id,
+            investor,
+            page,
+            entries: Vec::new(),
+            bump,
+            version: CONTRIBUTION_LEDGER_VERSION,
+        }
+    }
+}
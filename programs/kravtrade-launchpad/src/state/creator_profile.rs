@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// A creator's launches and lifetime results, for discovery without account scans
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorProfile {
+    /// Creator the profile belongs to
+    pub creator: Pubkey,
+    /// Number of launches created
+    pub launches_created: u64,
+    /// Number of launches finalized as successful
+    pub launches_succeeded: u64,
+    /// Total raised by successful launches in lamports
+    pub total_raised: u64,
+    /// IDs of the creator's launches in creation order; the account grows with each launch
+    #[max_len(0)]
+    pub launch_ids: Vec<u64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+impl CreatorProfile {
+    /// Space needed for a profile without launches
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Space needed for a profile listing `launch_count` launches
+    pub fn space(launch_count: usize) -> usize {
+        Self::LEN + launch_count * 8
+    }
+
+    /// Address of a creator's profile, for off-chain lookups
+    pub fn address(creator: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[CREATOR_PROFILE_SEED, creator.as_ref()], &crate::ID).0
+    }
+
+    /// Profile for a creator's first launch
+    pub fn new(creator: Pubkey, bump: u8) -> Self {
+        Self {
+            creator,
+            launches_created: 0,
+            launches_succeeded: 0,
+            total_raised: 0,
+            launch_ids: Vec::new(),
+            bump,
+            version: CREATOR_PROFILE_VERSION,
+        }
+    }
+
+    /// Add a newly created launch
+    pub fn record_launch(&mut self, launch_id: u64) -> Result<()> {
+        self.launch_ids.push(launch_id);
+        self.launches_created = self.launches_created
+            .checked_add(1)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Count a launch finalized as successful
+    pub fn record_success(&mut self, raised_amount: u64) -> Result<()> {
+        self.launches_succeeded = self.launches_succeeded
+            .checked_add(1)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        self.total_raised = self.total_raised
+            .checked_add(raised_amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
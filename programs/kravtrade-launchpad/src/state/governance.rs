@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Investor governance parameters for a launch
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct GovernanceConfig {
+    /// Share of allocated tokens that must vote (in basis points, 10000 = 100%)
+    pub quorum_percentage: u16,
+    /// Share of cast votes required to pass (in basis points, 10000 = 100%)
+    pub approval_threshold: u16,
+    /// Length of the voting period in seconds
+    pub voting_period: i64,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        GovernanceConfig {
+            quorum_percentage: DEFAULT_GOVERNANCE_QUORUM,
+            approval_threshold: DEFAULT_GOVERNANCE_THRESHOLD,
+            voting_period: DEFAULT_GOVERNANCE_VOTING_PERIOD,
+        }
+    }
+}
+
+impl GovernanceConfig {
+    /// Validate governance configuration parameters
+    pub fn validate(&self) -> Result<()> {
+        if self.quorum_percentage == 0 || self.quorum_percentage > BASIS_POINTS_MAX {
+            return Err(crate::errors::LaunchpadError::InvalidGovernanceConfig.into());
+        }
+
+        // Proposals need at least a simple majority
+        if self.approval_threshold < BASIS_POINTS_MAX / 2 || self.approval_threshold > BASIS_POINTS_MAX {
+            return Err(crate::errors::LaunchpadError::InvalidGovernanceConfig.into());
+        }
+
+        if self.voting_period < MIN_GOVERNANCE_VOTING_PERIOD
+            || self.voting_period > MAX_GOVERNANCE_VOTING_PERIOD
+        {
+            return Err(crate::errors::LaunchpadError::InvalidGovernanceConfig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Minimum number of votes for a proposal to be decided
+    pub fn calculate_quorum(&self, total_voting_power: u64) -> Result<u64> {
+        let quorum = (total_voting_power as u128)
+            .checked_mul(self.quorum_percentage as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS_MAX as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+
+        u64::try_from(quorum).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+
+    /// Check whether the votes in favour meet the approval threshold
+    pub fn is_approved(&self, votes_for: u64, votes_against: u64) -> bool {
+        let total_votes = votes_for as u128 + votes_against as u128;
+        if total_votes == 0 {
+            return false;
+        }
+
+        (votes_for as u128) * (BASIS_POINTS_MAX as u128)
+            >= total_votes * (self.approval_threshold as u128)
+    }
+}
+
+/// Action a proposal executes when it passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// Lengthen the token vesting schedule
+    ExtendVesting { additional_duration: i64 },
+    /// Freeze unreleased milestone escrow for investor refunds
+    TriggerRefund,
+    /// Send future creator proceeds to a new address
+    ReplacePayoutAddress { new_payout_address: Pubkey },
+}
+
+impl ProposalKind {
+    /// Calculate space needed for the largest variant
+    pub const LEN: usize =
+        1 + // variant
+        32; // largest payload (new_payout_address)
+}
+
+/// Status of a governance proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub enum ProposalStatus {
+    /// Investors are voting
+    #[default]
+    Voting,
+    /// Passed and its action has been applied
+    Executed,
+    /// Failed quorum or threshold
+    Defeated,
+}
+
+/// Investor governance proposal for a launch
+#[account]
+pub struct Proposal {
+    /// Launch ID the proposal belongs to
+    pub launch_id: u64,
+    /// Sequential proposal ID within the launch
+    pub proposal_id: u64,
+    /// Investor who created the proposal
+    pub proposer: Pubkey,
+    /// Action executed if the proposal passes
+    pub kind: ProposalKind,
+    /// Current status of the proposal
+    pub status: ProposalStatus,
+    /// Allocation-weighted votes in favour
+    pub votes_for: u64,
+    /// Allocation-weighted votes against
+    pub votes_against: u64,
+    /// Creation time (Unix timestamp)
+    pub created_at: i64,
+    /// End of the voting period (Unix timestamp)
+    pub voting_ends_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // launch_id
+        8 + // proposal_id
+        32 + // proposer
+        ProposalKind::LEN + // kind
+        1 + // status enum
+        8 + // votes_for
+        8 + // votes_against
+        8 + // created_at
+        8 + // voting_ends_at
+        1; // bump
+}
+
+/// Receipt recording an investor's vote on a proposal
+#[account]
+pub struct ProposalVote {
+    /// Wallet that contributed the position that voted
+    pub investor: Pubkey,
+    /// Proposal the vote was cast on
+    pub proposal: Pubkey,
+    /// Allocation-weighted voting power used
+    pub weight: u64,
+    /// Whether the vote approved the proposal
+    pub approve: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProposalVote {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // investor
+        32 + // proposal
+        8 + // weight
+        1 + // approve
+        1; // bump
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+/// Platform insurance fund fed by a share of platform fees
+#[account]
+pub struct InsuranceFund {
+    /// Total lamports deposited from platform fees
+    pub total_deposited: u64,
+    /// Total lamports committed to flagged launches
+    pub total_committed: u64,
+    /// Total lamports paid out to investors
+    pub total_paid: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_deposited
+        8 + // total_committed
+        8 + // total_paid
+        1; // bump
+
+    /// Lamports that are not yet committed to a flagged launch
+    pub fn available_balance(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_committed)
+    }
+
+    /// Record a fee deposit
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Commit part of the fund to compensate a flagged launch
+    pub fn commit(&mut self, amount: u64) -> Result<()> {
+        if amount > self.available_balance() {
+            return Err(crate::errors::LaunchpadError::InsufficientInsuranceFunds.into());
+        }
+        self.total_committed = self.total_committed
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record a compensation payout
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid = self.total_paid
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Compensation approved by the admin for investors of a flagged launch
+#[account]
+pub struct InsuranceClaim {
+    /// Launch ID this claim compensates
+    pub launch_id: u64,
+    /// Lamports committed from the insurance fund
+    pub compensation_pool: u64,
+    /// Launch contributions the pool is split across
+    pub total_contributions: u64,
+    /// Lamports paid out so far
+    pub total_paid: u64,
+    /// Timestamp the launch was flagged
+    pub flagged_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl InsuranceClaim {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // launch_id
+        8 + // compensation_pool
+        8 + // total_contributions
+        8 + // total_paid
+        8 + // flagged_at
+        1; // bump
+
+    /// Calculate compensation owed to an investor pro-rata to their contribution
+    pub fn calculate_compensation(&self, contribution_amount: u64) -> Result<u64> {
+        if self.total_contributions == 0 {
+            return Ok(0);
+        }
+
+        let compensation = (self.compensation_pool as u128)
+            .checked_mul(contribution_amount as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(self.total_contributions as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+
+        u64::try_from(compensation).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::INVESTOR_ACCOUNT_VERSION;
+use crate::state::LaunchStatus;
 
 /// Account tracking investor participation in a launch
 #[account]
+#[derive(InitSpace)]
 pub struct InvestorAccount {
     /// The investor's public key
     pub investor: Pubkey,
@@ -17,21 +21,148 @@ pub struct InvestorAccount {
     pub last_claim_time: i64,
     /// Whether the investor has been refunded (for failed launches)
     pub is_refunded: bool,
+    /// Whether insurance compensation has been claimed
+    pub insurance_claimed: bool,
+    /// Whether escrowed funds of a rejected milestone have been refunded
+    pub milestone_refunded: bool,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+    /// Entries recorded in the investor's contribution ledger (0 = no ledger kept)
+    pub ledger_entries: u64,
+    /// Timestamp of the last position transfer received (0 = none)
+    pub position_received_at: i64,
+    /// Mint of the position NFT (default = position not tokenized)
+    pub position_mint: Pubkey,
+    /// Referrer wallet the contributions are attributed to (default = none)
+    pub referrer: Pubkey,
+    /// Part of the contribution attributed to `referrer`, in lamports
+    pub referred_amount: u64,
+    /// Token allocation received by transfer; it carries no weight in votes opened at
+    /// or before `position_received_at`
+    pub received_allocation: u64,
 }
 
+// Accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(InvestorAccount::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
 impl InvestorAccount {
-    /// Calculate space needed for the account
-    pub const LEN: usize = 8 + // discriminator
-        32 + // investor
-        8 + // launch_id
-        8 + // contribution_amount
-        8 + // token_allocation
-        8 + // claimed_amount
-        8 + // last_claim_time
-        1 + // is_refunded
-        1; // bump
+    /// Space needed for the account, derived from the field types
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Set up an account just created by `init_if_needed`
+    pub fn initialize(&mut self, investor: Pubkey, launch_id: u64, bump: u8) {
+        self.investor = investor;
+        self.launch_id = launch_id;
+        self.contribution_amount = 0;
+        self.token_allocation = 0;
+        self.claimed_amount = 0;
+        self.last_claim_time = 0;
+        self.is_refunded = false;
+        self.insurance_claimed = false;
+        self.milestone_refunded = false;
+        self.bump = bump;
+        self.version = INVESTOR_ACCOUNT_VERSION;
+        self.ledger_entries = 0;
+        self.position_received_at = 0;
+        self.position_mint = Pubkey::default();
+        self.referrer = Pubkey::default();
+        self.referred_amount = 0;
+        self.received_allocation = 0;
+    }
+
+    /// Check if the position is represented by an NFT
+    pub fn has_position_nft(&self) -> bool {
+        self.position_mint != Pubkey::default()
+    }
+
+    /// Move `token_amount` of the allocation to `recipient`, with the same share of the
+    /// contribution and claimed tokens so the vesting progress of both positions is unchanged.
+    /// Returns the contribution and claimed tokens moved.
+    pub fn transfer_share(
+        &mut self,
+        recipient: &mut InvestorAccount,
+        token_amount: u64,
+        current_time: i64,
+    ) -> Result<(u64, u64)> {
+        if token_amount == 0 || token_amount > self.token_allocation {
+            return Err(crate::errors::LaunchpadError::InsufficientFunds.into());
+        }
+
+        let pro_rata = |value: u64| -> Result<u64> {
+            let share = (value as u128)
+                .checked_mul(token_amount as u128)
+                .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+                .checked_div(self.token_allocation as u128)
+                .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+            u64::try_from(share).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+        };
+        let contribution_moved = pro_rata(self.contribution_amount)?;
+        let claimed_moved = pro_rata(self.claimed_amount)?;
+        // The referral credit of the moved share stays with the referrer
+        let referred_moved = pro_rata(self.referred_amount)?;
+
+        self.contribution_amount -= contribution_moved;
+        self.token_allocation -= token_amount;
+        self.claimed_amount -= claimed_moved;
+        self.referred_amount -= referred_moved;
+        self.received_allocation = self.received_allocation.min(self.token_allocation);
+
+        recipient.contribution_amount = recipient.contribution_amount
+            .checked_add(contribution_moved)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        recipient.token_allocation = recipient.token_allocation
+            .checked_add(token_amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        recipient.claimed_amount = recipient.claimed_amount
+            .checked_add(claimed_moved)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        recipient.last_claim_time = recipient.last_claim_time.max(self.last_claim_time);
+        recipient.received_allocation = recipient.received_allocation
+            .checked_add(token_amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        recipient.position_received_at = current_time;
+
+        Ok((contribution_moved, claimed_moved))
+    }
+
+    /// Remove the referred share of a withdrawal of `amount` from the position, before
+    /// the contribution is reduced. Returns the referred volume to reverse.
+    pub fn withdraw_referred_share(&mut self, amount: u64) -> Result<u64> {
+        if self.referred_amount == 0 || self.contribution_amount == 0 {
+            return Ok(0);
+        }
+
+        let share = (self.referred_amount as u128)
+            .checked_mul(amount as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(self.contribution_amount as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        let share = u64::try_from(share)
+            .map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .min(self.referred_amount);
+
+        self.referred_amount -= share;
+        Ok(share)
+    }
+
+    /// Part of `value`, the position's allocation or contribution, that may vote in a vote
+    /// opened at `opened_at`. Allocation received since then may already have voted from the
+    /// sending wallet, so only the share the position held on its own counts.
+    pub fn vote_weight_since(&self, value: u64, opened_at: i64) -> Result<u64> {
+        if self.position_received_at < opened_at || self.token_allocation == 0 {
+            return Ok(value);
+        }
+
+        let held_allocation = self.token_allocation - self.received_allocation.min(self.token_allocation);
+        let weight = (value as u128)
+            .checked_mul(held_allocation as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(self.token_allocation as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        u64::try_from(weight).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
 
     /// Calculate the amount of tokens available for claiming based on vesting
     pub fn calculate_claimable_amount(
@@ -117,4 +248,26 @@ impl InvestorAccount {
     pub fn mark_refunded(&mut self) {
         self.is_refunded = true;
     }
+
+    /// Check if the position is fully claimed or refunded. On a successful launch this
+    /// includes the milestone refund and insurance compensation the position is owed.
+    pub fn is_settled(
+        &self,
+        status: &LaunchStatus,
+        milestone_refunds_enabled: bool,
+        insurance_flagged: bool,
+    ) -> bool {
+        let has_contribution = self.contribution_amount > 0;
+        match status {
+            LaunchStatus::Successful => {
+                self.claimed_amount >= self.token_allocation
+                    && !(milestone_refunds_enabled && has_contribution && !self.milestone_refunded)
+                    && !(insurance_flagged && has_contribution && !self.insurance_claimed)
+            }
+            LaunchStatus::Failed | LaunchStatus::Cancelled => {
+                self.is_refunded || self.contribution_amount == 0
+            }
+            _ => false,
+        }
+    }
 }
\ No newline at end of file
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::state::{VestingConfig};
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+use crate::state::{
+    VestingConfig, GovernanceConfig, WithdrawalConfig, TokenExtensionsConfig, MintAuthorityPolicy,
+    UnsoldTokenPolicy, PositionTransferPolicy,
+};
 
 /// Status of a token launch
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Default)]
 pub enum LaunchStatus {
     /// Awaiting admin approval
+    #[default]
     Pending,
     /// Presale is live and accepting contributions
     Active,
@@ -18,47 +24,9 @@ pub enum LaunchStatus {
     Paused,
 }
 
-impl Default for LaunchStatus {
-    fn default() -> Self {
-        LaunchStatus::Pending
-    }
-}
-
-/// Metadata for a token launch
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct LaunchMetadata {
-    /// Project description
-    pub description: String,
-    /// Project website URL
-    pub website: String,
-    /// Twitter handle
-    pub twitter: String,
-    /// Telegram link
-    pub telegram: String,
-    /// Discord link
-    pub discord: String,
-    /// Project logo URI
-    pub logo_uri: String,
-    /// Whitepaper or documentation link
-    pub documentation: String,
-}
-
-impl Default for LaunchMetadata {
-    fn default() -> Self {
-        LaunchMetadata {
-            description: String::new(),
-            website: String::new(),
-            twitter: String::new(),
-            telegram: String::new(),
-            discord: String::new(),
-            logo_uri: String::new(),
-            documentation: String::new(),
-        }
-    }
-}
-
 /// Configuration for a token launch
 #[account]
+#[derive(InitSpace)]
 pub struct LaunchConfig {
     /// Creator of the launch
     pub creator: Pubkey,
@@ -67,8 +35,10 @@ pub struct LaunchConfig {
     /// Unique launch identifier
     pub launch_id: u64,
     /// Token name
+    #[max_len(MAX_NAME_LENGTH)]
     pub name: String,
     /// Token symbol
+    #[max_len(MAX_SYMBOL_LENGTH)]
     pub symbol: String,
     /// Token decimals
     pub decimals: u8,
@@ -96,35 +66,60 @@ pub struct LaunchConfig {
     pub status: LaunchStatus,
     /// Vesting configuration
     pub vesting_config: VestingConfig,
-    /// Project metadata
-    pub metadata: LaunchMetadata,
+    /// Creator bond in lamports escrowed in this account
+    pub creator_bond: u64,
+    /// Contributions attributed to referrers who have not claimed their rewards, in lamports
+    pub referred_volume: u64,
+    /// Platform fee share reserved for referrers and not yet claimed, in lamports
+    pub referral_pool: u64,
+    /// Whether raised funds are released through a milestone schedule
+    pub uses_milestones: bool,
+    /// Investor governance parameters
+    pub governance_config: GovernanceConfig,
+    /// Address receiving creator proceeds
+    pub payout_address: Pubkey,
+    /// Number of governance proposals created
+    pub proposal_count: u64,
+    /// Investor accounts not yet closed
+    pub open_investor_accounts: u32,
+    /// Contribution withdrawal settings
+    pub withdrawal_config: WithdrawalConfig,
+    /// Whether unclaimed refunds were swept to the refund holding account
+    pub refunds_swept: bool,
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+    /// Token-2022 extensions enabled on the mint
+    pub token_extensions: TokenExtensionsConfig,
+    /// Whether tokens are sold from a deposited supply of an existing mint
+    pub uses_existing_mint: bool,
+    /// Tokens deposited into the vault for an existing-mint launch
+    pub deposited_tokens: u64,
+    /// What happens to the mint and freeze authority at finalization
+    pub mint_authority_policy: MintAuthorityPolicy,
+    /// What happens to presale tokens left unsold below the hard cap
+    pub unsold_token_policy: UnsoldTokenPolicy,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+    /// When investors may transfer their positions to another wallet
+    pub position_transfer_policy: PositionTransferPolicy,
+    /// Whether each position is represented by an NFT minted on the investor's first contribution
+    pub position_nfts: bool,
+    /// Guaranteed allocations reserved by stakers and not yet contributed, in lamports.
+    /// Only held back from other contributors until the reservation deadline.
+    pub reserved_allocation: u64,
+    /// Whether `finalize_launch` has settled the launch. A launch reaching its hard cap is
+    /// `Successful` before it is finalized.
+    pub finalized: bool,
 }
 
+// Launch configs are created by CPI, which caps the size of a new account
+const _: () = assert!(LaunchConfig::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
 impl LaunchConfig {
-    /// Calculate space needed for the account
-    pub const LEN: usize = 8 + // discriminator
-        32 + // creator
-        32 + // token_mint
-        8 + // launch_id
-        4 + 50 + // name (max 50 chars)
-        4 + 10 + // symbol (max 10 chars)
-        1 + // decimals
-        8 + // total_supply
-        8 + // presale_price
-        8 + // min_contribution
-        8 + // max_contribution
-        8 + // soft_cap
-        8 + // hard_cap
-        8 + // start_time
-        8 + // end_time
-        8 + // total_raised
-        4 + // contributor_count
-        1 + // status enum
-        VestingConfig::LEN + // vesting_config
-        LaunchMetadata::LEN + // metadata
-        1; // bump
+    /// Space needed for the account, derived from the field types and string limits
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
 
     /// Check if the launch is currently active
     pub fn is_active(&self) -> bool {
@@ -156,29 +151,92 @@ impl LaunchConfig {
         Ok(tokens)
     }
 
-    /// Validate contribution amount
-    pub fn validate_contribution(&self, amount: u64) -> Result<()> {
+    /// Tokens allocated to the presale, i.e. a sale that fills the hard cap.
+    /// Existing-mint launches must deposit this much before approval.
+    pub fn presale_allocation(&self) -> Result<u64> {
+        self.calculate_token_allocation(self.hard_cap)
+    }
+
+    /// Check if an existing-mint launch holds enough inventory to be approved
+    pub fn is_funded(&self) -> Result<bool> {
+        if !self.uses_existing_mint {
+            return Ok(true);
+        }
+        Ok(self.deposited_tokens >= self.presale_allocation()?)
+    }
+
+    /// Move the escrowed creator bond out of the launch account.
+    /// Returns the amount moved so callers can account for it.
+    pub fn release_creator_bond(
+        &mut self,
+        launch_account: &AccountInfo,
+        recipient: &AccountInfo,
+    ) -> Result<u64> {
+        let bond = self.creator_bond;
+        if bond > 0 {
+            **launch_account.try_borrow_mut_lamports()? -= bond;
+            **recipient.try_borrow_mut_lamports()? += bond;
+            self.creator_bond = 0;
+        }
+        Ok(bond)
+    }
+
+    /// Unix timestamp from which the launch can be closed with investor accounts still open:
+    /// the sunset period after the presale ends or, for successful launches, after vesting
+    /// ends, including extensions voted by investors
+    pub fn sunset_time(&self) -> Result<i64> {
+        let settled_at = if self.status == LaunchStatus::Successful {
+            self.end_time
+                .checked_add(self.vesting_config.vesting_duration.max(0))
+                .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+        } else {
+            self.end_time
+        };
+        settled_at
+            .checked_add(LAUNCH_SUNSET_PERIOD)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+
+    /// Check if the launch has been finalized or cancelled
+    pub fn is_concluded(&self) -> bool {
+        self.finalized || self.status == LaunchStatus::Cancelled
+    }
+
+    /// Validate a contribution of `amount` bringing the investor's total to `investor_total`.
+    /// `reserved` of the amount is covered by the investor's own reservation; the rest must
+    /// fit under the hard cap without using capacity reserved by other stakers.
+    pub fn validate_contribution(
+        &self,
+        amount: u64,
+        investor_total: u64,
+        max_contribution: u64,
+        reserved: u64,
+        current_time: i64,
+    ) -> Result<()> {
         if amount < self.min_contribution {
             return Err(crate::errors::LaunchpadError::ContributionTooLow.into());
         }
-        if amount > self.max_contribution {
+        if investor_total > max_contribution {
             return Err(crate::errors::LaunchpadError::ContributionTooHigh.into());
         }
-        if self.total_raised.checked_add(amount).unwrap_or(u64::MAX) > self.hard_cap {
+        if amount.saturating_sub(reserved) > self.unreserved_capacity(current_time) {
             return Err(crate::errors::LaunchpadError::HardCapExceeded.into());
         }
         Ok(())
     }
-}
 
-impl LaunchMetadata {
-    /// Calculate space needed for metadata
-    pub const LEN: usize = 
-        4 + 500 + // description (max 500 chars)
-        4 + 100 + // website (max 100 chars)
-        4 + 50 + // twitter (max 50 chars)
-        4 + 100 + // telegram (max 100 chars)
-        4 + 100 + // discord (max 100 chars)
-        4 + 200 + // logo_uri (max 200 chars)
-        4 + 200; // documentation (max 200 chars)
-}
\ No newline at end of file
+    /// Unix timestamp at which unused reservations lapse and their capacity opens to everyone
+    pub fn reservation_deadline(&self) -> i64 {
+        self.end_time.saturating_sub(RESERVATION_CUTOFF)
+    }
+
+    /// Lamports that can still be raised beyond the reserved guaranteed allocations
+    pub fn unreserved_capacity(&self, current_time: i64) -> u64 {
+        let remaining = self.hard_cap.saturating_sub(self.total_raised);
+        if current_time >= self.reservation_deadline() {
+            remaining
+        } else {
+            remaining.saturating_sub(self.reserved_allocation)
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+
+/// Metadata for a token launch
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default)]
+pub struct LaunchMetadata {
+    /// Project description
+    #[max_len(MAX_DESCRIPTION_LENGTH)]
+    pub description: String,
+    /// Project website URL
+    #[max_len(MAX_URL_LENGTH)]
+    pub website: String,
+    /// Twitter handle
+    #[max_len(MAX_SOCIAL_LENGTH)]
+    pub twitter: String,
+    /// Telegram link
+    #[max_len(MAX_SOCIAL_LENGTH)]
+    pub telegram: String,
+    /// Discord link
+    #[max_len(MAX_SOCIAL_LENGTH)]
+    pub discord: String,
+    /// Project logo URI
+    #[max_len(MAX_URL_LENGTH)]
+    pub logo_uri: String,
+    /// Whitepaper or documentation link
+    #[max_len(MAX_URL_LENGTH)]
+    pub documentation: String,
+}
+
+impl LaunchMetadata {
+    /// Validate metadata string lengths
+    pub fn validate(&self) -> Result<()> {
+        let limits = [
+            (&self.description, MAX_DESCRIPTION_LENGTH),
+            (&self.website, MAX_URL_LENGTH),
+            (&self.twitter, MAX_SOCIAL_LENGTH),
+            (&self.telegram, MAX_SOCIAL_LENGTH),
+            (&self.discord, MAX_SOCIAL_LENGTH),
+            (&self.logo_uri, MAX_URL_LENGTH),
+            (&self.documentation, MAX_URL_LENGTH),
+        ];
+
+        if limits.iter().any(|(value, max_len)| value.len() > *max_len) {
+            return Err(crate::errors::LaunchpadError::MetadataTooLong.into());
+        }
+
+        Ok(())
+    }
+
+    /// Serialized size of this metadata (length prefix plus bytes of each string)
+    pub fn serialized_len(&self) -> usize {
+        [
+            &self.description,
+            &self.website,
+            &self.twitter,
+            &self.telegram,
+            &self.discord,
+            &self.logo_uri,
+            &self.documentation,
+        ]
+        .iter()
+        .map(|value| 4 + value.len())
+        .sum()
+    }
+}
+
+/// Project metadata of a launch, kept apart from `LaunchConfig` so the hot paths
+/// do not deserialize it and so it can be resized when edited
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchMetadataAccount {
+    /// Launch this metadata describes
+    pub launch_id: u64,
+    /// Project metadata
+    pub metadata: LaunchMetadata,
+    /// Whether the admin allows the creator to edit metadata after approval
+    pub updates_allowed: bool,
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+// Metadata accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(LaunchMetadataAccount::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+impl LaunchMetadataAccount {
+    /// Space needed for the largest metadata, derived from the field types and string limits
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Space needed to store the given metadata
+    pub fn space(metadata: &LaunchMetadata) -> usize {
+        Self::LEN - LaunchMetadata::INIT_SPACE + metadata.serialized_len()
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+
+/// Status bucket a launch is listed under
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegistryBucket {
+    /// Awaiting admin review
+    #[default]
+    Pending,
+    /// Approved and not yet finalized, including paused launches
+    Active,
+    /// Finalized, rejected or cancelled
+    Ended,
+}
+
+impl RegistryBucket {
+    /// Seed distinguishing the pages of each bucket
+    pub const fn seed(self) -> &'static [u8] {
+        match self {
+            RegistryBucket::Pending => &[0],
+            RegistryBucket::Active => &[1],
+            RegistryBucket::Ended => &[2],
+        }
+    }
+}
+
+/// One page of launch IDs in a status bucket. Launch `n` is always listed on page
+/// `n / REGISTRY_PAGE_SIZE`, so pages never fill up and clients can list a bucket
+/// by reading pages 0 through `last_launch_id / REGISTRY_PAGE_SIZE`.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchRegistry {
+    /// Status bucket of the page
+    pub bucket: RegistryBucket,
+    /// Page index within the bucket
+    pub page: u64,
+    /// Launch IDs currently in the bucket, unordered
+    #[max_len(REGISTRY_PAGE_SIZE)]
+    pub launch_ids: Vec<u64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+// Pages are created by CPI, which caps the size of a new account
+const _: () = assert!(LaunchRegistry::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+impl LaunchRegistry {
+    /// Space needed for the account, derived from the field types and page size
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Page a launch is listed on
+    pub fn page_for(launch_id: u64) -> u64 {
+        launch_id / REGISTRY_PAGE_SIZE
+    }
+
+    /// Address of a page, for off-chain listing
+    pub fn address(bucket: RegistryBucket, page: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[LAUNCH_REGISTRY_SEED, bucket.seed(), page.to_le_bytes().as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Set up a page just created by `init_if_needed`
+    fn init_page(&mut self, bucket: RegistryBucket, launch_id: u64, bump: u8) {
+        if self.version == 0 {
+            self.bucket = bucket;
+            self.page = Self::page_for(launch_id);
+            self.bump = bump;
+            self.version = LAUNCH_REGISTRY_VERSION;
+        }
+    }
+
+    /// List a launch on its page of `bucket`
+    pub fn list(&mut self, bucket: RegistryBucket, launch_id: u64, bump: u8) {
+        self.init_page(bucket, launch_id, bump);
+        if !self.launch_ids.contains(&launch_id) {
+            self.launch_ids.push(launch_id);
+        }
+    }
+
+    /// Remove a launch from its page of `bucket`. Launches created before the
+    /// registry existed were never listed, so a missing ID is ignored.
+    pub fn unlist(&mut self, bucket: RegistryBucket, launch_id: u64, bump: u8) {
+        self.init_page(bucket, launch_id, bump);
+        if let Some(index) = self.launch_ids.iter().position(|id| *id == launch_id) {
+            self.launch_ids.swap_remove(index);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Status of a milestone tranche
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub enum MilestoneStatus {
+    /// Waiting for unlock time or for the creator to open a vote
+    #[default]
+    Locked,
+    /// Investors are voting on the release
+    Voting,
+    /// Tranche has been paid out to the creator
+    Released,
+    /// Investors voted against the release, escrow is refundable
+    Rejected,
+}
+
+/// Milestone parameters supplied by the creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneParams {
+    /// Earliest time a release vote can be opened (Unix timestamp)
+    pub unlock_time: i64,
+    /// Share of escrowed funds released (in basis points, 10000 = 100%)
+    pub release_percentage: u16,
+}
+
+/// A single tranche of escrowed funds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Milestone {
+    /// Earliest time a release vote can be opened (Unix timestamp)
+    pub unlock_time: i64,
+    /// Share of escrowed funds released (in basis points, 10000 = 100%)
+    pub release_percentage: u16,
+    /// Current status of the tranche
+    pub status: MilestoneStatus,
+    /// End of the current vote (Unix timestamp)
+    pub voting_ends_at: i64,
+    /// Contribution-weighted votes in favour
+    pub votes_for: u64,
+    /// Contribution-weighted votes against
+    pub votes_against: u64,
+    /// Incremented each time a vote is opened
+    pub vote_round: u8,
+}
+
+impl Milestone {
+    /// Calculate space needed for a milestone
+    pub const LEN: usize =
+        8 + // unlock_time
+        2 + // release_percentage
+        1 + // status enum
+        8 + // voting_ends_at
+        8 + // votes_for
+        8 + // votes_against
+        1; // vote_round
+}
+
+/// Milestone schedule and escrow state for a launch
+#[account]
+pub struct MilestoneSchedule {
+    /// Launch ID this schedule belongs to
+    pub launch_id: u64,
+    /// Ordered tranches
+    pub milestones: Vec<Milestone>,
+    /// Creator proceeds held in the treasury at finalization
+    pub escrowed_amount: u64,
+    /// Amount released to the creator so far
+    pub released_amount: u64,
+    /// Unreleased funds frozen for investor refunds after a failed vote
+    pub refund_pool: u64,
+    /// Whether investors can claim refunds of unreleased funds
+    pub refunds_enabled: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MilestoneSchedule {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // launch_id
+        4 + MAX_MILESTONES * Milestone::LEN + // milestones
+        8 + // escrowed_amount
+        8 + // released_amount
+        8 + // refund_pool
+        1 + // refunds_enabled
+        1; // bump
+
+    /// Validate milestone parameters against the launch end time
+    pub fn validate_params(params: &[MilestoneParams], end_time: i64) -> Result<()> {
+        if params.is_empty() || params.len() > MAX_MILESTONES {
+            return Err(crate::errors::LaunchpadError::InvalidMilestoneSchedule.into());
+        }
+
+        let mut total_percentage: u32 = 0;
+        let mut previous_unlock = end_time;
+        for milestone in params {
+            // Unlock times must be after the sale and strictly increasing
+            if milestone.unlock_time <= previous_unlock {
+                return Err(crate::errors::LaunchpadError::InvalidMilestoneSchedule.into());
+            }
+            if milestone.release_percentage == 0 {
+                return Err(crate::errors::LaunchpadError::InvalidMilestoneSchedule.into());
+            }
+            previous_unlock = milestone.unlock_time;
+            total_percentage += milestone.release_percentage as u32;
+        }
+
+        // Tranches must release exactly the full escrow
+        if total_percentage != BASIS_POINTS_MAX as u32 {
+            return Err(crate::errors::LaunchpadError::InvalidMilestoneSchedule.into());
+        }
+
+        Ok(())
+    }
+
+    /// Funds still held in escrow
+    pub fn unreleased_amount(&self) -> u64 {
+        self.escrowed_amount.saturating_sub(self.released_amount)
+    }
+
+    /// Freeze the remaining escrow for investor refunds, cancelling any open release vote
+    /// so it can no longer pay out of the refund pool
+    pub fn enable_refunds(&mut self) {
+        for milestone in self.milestones.iter_mut() {
+            if milestone.status == MilestoneStatus::Voting {
+                milestone.status = MilestoneStatus::Locked;
+                milestone.voting_ends_at = 0;
+            }
+        }
+        self.refund_pool = self.unreleased_amount();
+        self.refunds_enabled = true;
+    }
+
+    /// Check that every milestone before `index` has been released
+    pub fn previous_released(&self, index: usize) -> bool {
+        self.milestones[..index]
+            .iter()
+            .all(|milestone| milestone.status == MilestoneStatus::Released)
+    }
+
+    /// Calculate the amount released by the milestone at `index`
+    pub fn calculate_release_amount(&self, index: usize) -> Result<u64> {
+        // The final tranche releases whatever is left to avoid rounding dust
+        if index == self.milestones.len() - 1 {
+            return Ok(self.unreleased_amount());
+        }
+
+        self.escrowed_amount
+            .checked_mul(self.milestones[index].release_percentage as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS_MAX as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+
+    /// Calculate an investor's share of the refund pool
+    pub fn calculate_refund(&self, contribution_amount: u64, total_raised: u64) -> Result<u64> {
+        if total_raised == 0 {
+            return Ok(0);
+        }
+
+        let refund = (self.refund_pool as u128)
+            .checked_mul(contribution_amount as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(total_raised as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+
+        u64::try_from(refund).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+}
+
+/// Receipt recording an investor's vote on a milestone
+#[account]
+pub struct MilestoneVote {
+    /// Wallet that contributed the position that voted
+    pub investor: Pubkey,
+    /// Launch ID of the milestone
+    pub launch_id: u64,
+    /// Index of the milestone voted on
+    pub milestone_index: u8,
+    /// Vote round the receipt applies to
+    pub vote_round: u8,
+    /// Contribution-weighted voting power used
+    pub weight: u64,
+    /// Whether the vote approved the release
+    pub approve: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MilestoneVote {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // investor
+        8 + // launch_id
+        1 + // milestone_index
+        1 + // vote_round
+        8 + // weight
+        1 + // approve
+        1; // bump
+}
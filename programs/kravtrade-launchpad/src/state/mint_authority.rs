@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// What happens to the mint and freeze authority when a launch is finalized
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Default)]
+pub enum MintAuthorityPolicy {
+    /// Permanently revoke the authorities once the full supply is minted
+    #[default]
+    Revoke,
+    /// Hand the authorities to a creator-chosen address
+    Transfer { new_authority: Pubkey },
+    /// Keep the authorities on the launch PDA (required when tokens are minted at claim time)
+    Retain,
+}
+
+impl MintAuthorityPolicy {
+    /// Validate the policy against how the launch mints its tokens
+    pub fn validate(&self, uses_existing_mint: bool, non_transferable: bool) -> Result<()> {
+        match self {
+            // The launch never holds the authority of an existing mint, and
+            // non-transferable tokens are minted as investors claim
+            MintAuthorityPolicy::Revoke | MintAuthorityPolicy::Transfer { .. }
+                if uses_existing_mint || non_transferable =>
+            {
+                Err(crate::errors::LaunchpadError::InvalidMintAuthorityPolicy.into())
+            }
+            MintAuthorityPolicy::Transfer { new_authority } if *new_authority == Pubkey::default() => {
+                Err(crate::errors::LaunchpadError::InvalidMintAuthorityPolicy.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Authority to install at finalization (`Some(None)` revokes).
+    /// Returns `None` for `Retain`, which leaves the mint untouched.
+    pub fn new_authority(&self) -> Option<Option<Pubkey>> {
+        match self {
+            MintAuthorityPolicy::Revoke => Some(None),
+            MintAuthorityPolicy::Transfer { new_authority } => Some(Some(*new_authority)),
+            MintAuthorityPolicy::Retain => None,
+        }
+    }
+}
@@ -1,9 +1,41 @@
 pub mod launch_config;
 pub mod investor_account;
 pub mod platform_config;
+pub mod platform_stats;
 pub mod vesting;
+pub mod referrer;
+pub mod insurance_fund;
+pub mod milestone;
+pub mod governance;
+pub mod withdrawal;
+pub mod refund_holding;
+pub mod token_extensions;
+pub mod mint_authority;
+pub mod unsold_token_policy;
+pub mod position_transfer;
+pub mod launch_metadata;
+pub mod creator_profile;
+pub mod launch_registry;
+pub mod contribution_ledger;
+pub mod staking;
 
 pub use launch_config::*;
 pub use investor_account::*;
 pub use platform_config::*;
-pub use vesting::*;
\ No newline at end of file
+pub use platform_stats::*;
+pub use vesting::*;
+pub use referrer::*;
+pub use insurance_fund::*;
+pub use milestone::*;
+pub use governance::*;
+pub use withdrawal::*;
+pub use refund_holding::*;
+pub use token_extensions::*;
+pub use mint_authority::*;
+pub use unsold_token_policy::*;
+pub use position_transfer::*;
+pub use launch_metadata::*;
+pub use creator_profile::*;
+pub use launch_registry::*;
+pub use contribution_ledger::*;
+pub use staking::*;
\ No newline at end of file
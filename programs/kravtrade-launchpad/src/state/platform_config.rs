@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::MAX_STAKING_TIERS;
+use crate::state::StakingTier;
 
 /// Global platform configuration
 #[account]
+#[derive(InitSpace)]
 pub struct PlatformConfig {
     /// Platform administrator
     pub admin: Pubkey,
@@ -15,32 +19,72 @@ pub struct PlatformConfig {
     pub max_launch_duration: i64,
     /// Minimum soft cap amount in lamports
     pub min_soft_cap: u64,
+    /// Non-refundable fee in lamports charged on launch creation
+    pub listing_fee: u64,
+    /// Refundable bond in lamports escrowed per launch (0 = disabled)
+    pub creator_bond_amount: u64,
+    /// Share of the platform fee paid to referrers in basis points
+    pub referral_fee_share: u16,
+    /// Share of the platform fee deposited into the insurance fund in basis points
+    pub insurance_fee_share: u16,
+    /// Hard cap in lamports at or above which a milestone schedule is required (0 = never)
+    pub milestone_threshold: u64,
+    /// Seconds after a failed launch ends before unclaimed refunds can be swept (0 = never)
+    pub refund_window: i64,
     /// Whether the platform is paused
     pub is_paused: bool,
-    /// Total number of launches created
+    /// Number of launches finalized as successful (superseded by `PlatformStats`, no longer updated)
     pub total_launches: u64,
-    /// Total amount raised across all launches
+    /// Total amount raised across all launches (superseded by `PlatformStats`, no longer updated)
     pub total_raised: u64,
-    /// Total fees collected by platform
+    /// Total fees collected by platform (superseded by `PlatformStats`, no longer updated)
     pub total_fees_collected: u64,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+    /// Identifier assigned to the most recent launch (launch IDs start at 1)
+    pub last_launch_id: u64,
+    /// Mint of the platform token staked for allocation tiers (default = staking not configured)
+    pub stake_mint: Pubkey,
+    /// Allocation tiers by stake, in increasing order of minimum stake
+    #[max_len(MAX_STAKING_TIERS)]
+    pub staking_tiers: Vec<StakingTier>,
+}
+
+// Accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(PlatformConfig::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+/// Platform settings to change with `update_platform_config`; `None` keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PlatformConfigUpdate {
+    /// Platform fee percentage in basis points
+    pub platform_fee_percentage: Option<u16>,
+    /// Minimum launch duration in seconds
+    pub min_launch_duration: Option<i64>,
+    /// Maximum launch duration in seconds
+    pub max_launch_duration: Option<i64>,
+    /// Minimum soft cap in lamports
+    pub min_soft_cap: Option<u64>,
+    /// Listing fee in lamports charged on launch creation
+    pub listing_fee: Option<u64>,
+    /// Refundable bond in lamports escrowed per launch (0 = disabled)
+    pub creator_bond_amount: Option<u64>,
+    /// Share of the platform fee paid to referrers in basis points
+    pub referral_fee_share: Option<u16>,
+    /// Share of the platform fee deposited into the insurance fund in basis points
+    pub insurance_fee_share: Option<u16>,
+    /// Hard cap in lamports at or above which a milestone schedule is required (0 = never)
+    pub milestone_threshold: Option<u64>,
+    /// Seconds after a failed launch ends before unclaimed refunds can be swept (0 = never)
+    pub refund_window: Option<i64>,
+    /// Launch ID to skip to, so the next launch is assigned the ID after it
+    pub last_launch_id: Option<u64>,
 }
 
 impl PlatformConfig {
-    /// Calculate space needed for the account
-    pub const LEN: usize = 8 + // discriminator
-        32 + // admin
-        32 + // treasury
-        2 + // platform_fee_percentage
-        8 + // min_launch_duration
-        8 + // max_launch_duration
-        8 + // min_soft_cap
-        1 + // is_paused
-        8 + // total_launches
-        8 + // total_raised
-        8 + // total_fees_collected
-        1; // bump
+    /// Space needed for the account, derived from the field types and tier limit
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
 
     /// Validate platform configuration parameters
     pub fn validate_config(
@@ -80,6 +124,29 @@ impl PlatformConfig {
             .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
     }
 
+    /// Calculate the referrer share carved out of a platform fee
+    pub fn calculate_referral_share(&self, platform_fee: u64) -> Result<u64> {
+        platform_fee
+            .checked_mul(self.referral_fee_share as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+
+    /// Calculate the insurance fund share carved out of a platform fee
+    pub fn calculate_insurance_share(&self, platform_fee: u64) -> Result<u64> {
+        platform_fee
+            .checked_mul(self.insurance_fee_share as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+
+    /// Check if a launch with the given hard cap must use milestone escrow
+    pub fn requires_milestones(&self, hard_cap: u64) -> bool {
+        self.milestone_threshold > 0 && hard_cap >= self.milestone_threshold
+    }
+
     /// Validate launch duration against platform limits
     pub fn validate_launch_duration(&self, start_time: i64, end_time: i64) -> Result<()> {
         let duration = end_time - start_time;
@@ -103,21 +170,28 @@ impl PlatformConfig {
         Ok(())
     }
 
-    /// Update platform statistics
-    pub fn update_stats(&mut self, raised_amount: u64, fee_amount: u64) -> Result<()> {
-        self.total_launches = self.total_launches
+    /// Identifier the next created launch will receive
+    pub fn next_launch_id(&self) -> u64 {
+        self.last_launch_id.saturating_add(1)
+    }
+
+    /// Assign the next launch ID
+    pub fn allocate_launch_id(&mut self) -> Result<u64> {
+        self.last_launch_id = self.last_launch_id
             .checked_add(1)
             .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
-        
-        self.total_raised = self.total_raised
-            .checked_add(raised_amount)
-            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
-        
-        self.total_fees_collected = self.total_fees_collected
-            .checked_add(fee_amount)
-            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
 
-        Ok(())
+        Ok(self.last_launch_id)
+    }
+
+    /// Check if the refund window of a launch ending at `end_time` has closed
+    pub fn is_refund_window_closed(&self, end_time: i64, current_time: i64) -> bool {
+        self.refund_window > 0 && current_time > end_time.saturating_add(self.refund_window)
+    }
+
+    /// Check if the admin has configured staking
+    pub fn is_staking_configured(&self) -> bool {
+        self.stake_mint != Pubkey::default()
     }
 
     /// Check if platform operations are allowed
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+use crate::state::PlatformConfig;
+
+/// Platform-wide statistics, updated by every instruction that changes them
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformStats {
+    /// Number of launches created
+    pub launches_created: u64,
+    /// Number of launches finalized as successful
+    pub launches_succeeded: u64,
+    /// Number of launches finalized below their soft cap
+    pub launches_failed: u64,
+    /// Number of launches cancelled after approval
+    pub launches_cancelled: u64,
+    /// Number of launches rejected in review
+    pub launches_rejected: u64,
+    /// Investor positions opened by a contribution or a position transfer, summed over launches. A wallet contributing
+    /// to several launches is counted once per launch, so this is not a count of unique wallets.
+    pub launch_contributors: u64,
+    /// Total raised by successful launches in lamports
+    pub total_raised: u64,
+    /// Total refunded to investors in lamports, including milestone refunds
+    pub total_refunded: u64,
+    /// Fees paid into the platform treasury in lamports
+    pub fees_accrued: u64,
+    /// Fees withdrawn from the platform treasury by `collect_fees` in lamports
+    pub fees_withdrawn: u64,
+    /// Presale tokens claimed by or distributed to investors, in base units summed across mints
+    pub tokens_claimed: u128,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+// Accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(PlatformStats::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+impl PlatformStats {
+    /// Space needed for the account, derived from the field types
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Statistics seeded from the counters `PlatformConfig` kept before this account existed
+    pub fn from_config(platform_config: &PlatformConfig, bump: u8) -> Self {
+        Self {
+            // Only successful finalizations were counted before this account existed
+            launches_created: platform_config.total_launches,
+            launches_succeeded: platform_config.total_launches,
+            launches_failed: 0,
+            launches_cancelled: 0,
+            launches_rejected: 0,
+            launch_contributors: 0,
+            total_raised: platform_config.total_raised,
+            total_refunded: 0,
+            fees_accrued: platform_config.total_fees_collected,
+            fees_withdrawn: 0,
+            tokens_claimed: 0,
+            bump,
+            version: PLATFORM_STATS_VERSION,
+        }
+    }
+
+    /// Count a newly created launch and its listing fee
+    pub fn record_launch_created(&mut self, listing_fee: u64) -> Result<()> {
+        self.launches_created = increment(self.launches_created, 1)?;
+        self.record_fees(listing_fee)
+    }
+
+    /// Count a launch finalized as successful
+    pub fn record_success(&mut self, raised_amount: u64, fee_amount: u64) -> Result<()> {
+        self.launches_succeeded = increment(self.launches_succeeded, 1)?;
+        self.total_raised = increment(self.total_raised, raised_amount)?;
+        self.record_fees(fee_amount)
+    }
+
+    /// Count a launch finalized below its soft cap and its slashed bond
+    pub fn record_failure(&mut self, bond_slashed: u64) -> Result<()> {
+        self.launches_failed = increment(self.launches_failed, 1)?;
+        self.record_fees(bond_slashed)
+    }
+
+    /// Count a cancelled launch and its slashed bond
+    pub fn record_cancellation(&mut self, bond_slashed: u64) -> Result<()> {
+        self.launches_cancelled = increment(self.launches_cancelled, 1)?;
+        self.record_fees(bond_slashed)
+    }
+
+    /// Count a rejected launch and its slashed bond
+    pub fn record_rejection(&mut self, bond_slashed: u64) -> Result<()> {
+        self.launches_rejected = increment(self.launches_rejected, 1)?;
+        self.record_fees(bond_slashed)
+    }
+
+    /// Count an investor position opened in a launch by a contribution or a transfer
+    pub fn record_contributor(&mut self) -> Result<()> {
+        self.launch_contributors = increment(self.launch_contributors, 1)?;
+        Ok(())
+    }
+
+    /// Record lamports refunded to an investor
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.total_refunded = increment(self.total_refunded, amount)?;
+        Ok(())
+    }
+
+    /// Record fees paid into the platform treasury
+    pub fn record_fees(&mut self, amount: u64) -> Result<()> {
+        self.fees_accrued = increment(self.fees_accrued, amount)?;
+        Ok(())
+    }
+
+    /// Record fees withdrawn from the platform treasury
+    pub fn record_fees_withdrawn(&mut self, amount: u64) -> Result<()> {
+        self.fees_withdrawn = increment(self.fees_withdrawn, amount)?;
+        Ok(())
+    }
+
+    /// Record presale tokens released to an investor
+    pub fn record_tokens_claimed(&mut self, amount: u64) -> Result<()> {
+        self.tokens_claimed = self.tokens_claimed
+            .checked_add(amount as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+fn increment(value: u64, amount: u64) -> Result<u64> {
+    value
+        .checked_add(amount)
+        .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow.into())
+}
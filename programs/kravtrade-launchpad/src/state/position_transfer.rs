@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::LaunchStatus;
+
+/// When investors may move their positions to another wallet
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Default)]
+pub enum PositionTransferPolicy {
+    /// Positions stay with the contributing wallet
+    #[default]
+    Disabled,
+    /// Positions can move during the sale and after a successful finalization
+    Anytime,
+    /// Positions can only move after a successful finalization
+    AfterFinalization,
+}
+
+impl PositionTransferPolicy {
+    /// Check if positions can be transferred while the launch has `status`. A launch
+    /// reaching its hard cap is `Successful` before it is `finalized`.
+    pub fn allows(&self, status: &LaunchStatus, finalized: bool) -> bool {
+        match self {
+            PositionTransferPolicy::Disabled => false,
+            PositionTransferPolicy::Anytime => {
+                matches!(status, LaunchStatus::Active | LaunchStatus::Successful)
+            }
+            PositionTransferPolicy::AfterFinalization => {
+                finalized && *status == LaunchStatus::Successful
+            }
+        }
+    }
+}
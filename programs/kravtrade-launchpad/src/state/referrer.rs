@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+/// Account tracking volume referred to a launch by a single referrer
+#[account]
+pub struct Referrer {
+    /// The referrer's public key
+    pub referrer: Pubkey,
+    /// Launch ID this referrer is registered for
+    pub launch_id: u64,
+    /// Total contributions referred in lamports
+    pub referred_volume: u64,
+    /// Number of referred contributions
+    pub referral_count: u32,
+    /// Rewards paid out in lamports
+    pub claimed_amount: u64,
+    /// Whether rewards have been claimed
+    pub rewards_claimed: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Referrer {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // referrer
+        8 + // launch_id
+        8 + // referred_volume
+        4 + // referral_count
+        8 + // claimed_amount
+        1 + // rewards_claimed
+        1; // bump
+
+    /// Record a referred contribution
+    pub fn record_referral(&mut self, amount: u64) -> Result<()> {
+        self.referred_volume = self.referred_volume
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        self.referral_count = self.referral_count
+            .checked_add(1)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Reverse referred volume withdrawn before the sale ended
+    pub fn reverse_referral(&mut self, amount: u64) -> Result<()> {
+        self.referred_volume = self.referred_volume
+            .checked_sub(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Calculate this referrer's share of the launch referral pool
+    pub fn calculate_reward(&self, referral_pool: u64, total_referred_volume: u64) -> Result<u64> {
+        if total_referred_volume == 0 {
+            return Ok(0);
+        }
+
+        let reward = (referral_pool as u128)
+            .checked_mul(self.referred_volume as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(total_referred_volume as u128)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+
+        u64::try_from(reward).map_err(|_| crate::errors::LaunchpadError::ArithmeticOverflow.into())
+    }
+}
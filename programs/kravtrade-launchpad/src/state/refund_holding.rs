@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Holds unclaimed refunds swept from a failed launch treasury
+#[account]
+pub struct RefundHolding {
+    /// Launch ID the refunds belong to
+    pub launch_id: u64,
+    /// Lamports swept from the launch treasury
+    pub swept_amount: u64,
+    /// Lamports paid out to investors since the sweep
+    pub claimed_amount: u64,
+    /// Time of the sweep (Unix timestamp)
+    pub swept_at: i64,
+    /// Presale price of the launch, recorded in ledgers after the launch is closed
+    pub presale_price: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RefundHolding {
+    /// Calculate space needed for the account
+    pub const LEN: usize = 8 + // discriminator
+        8 + // launch_id
+        8 + // swept_amount
+        8 + // claimed_amount
+        8 + // swept_at
+        8 + // presale_price
+        1; // bump
+
+    /// Pay a late refund out of the holding account
+    pub fn pay_refund(&mut self, holding_account: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+        if amount > self.swept_amount.saturating_sub(self.claimed_amount) {
+            return Err(crate::errors::LaunchpadError::InsufficientFunds.into());
+        }
+
+        **holding_account.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+
+        self.claimed_amount = self.claimed_amount
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
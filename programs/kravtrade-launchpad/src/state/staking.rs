@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::constants::*;
+
+/// Allocation tier unlocked by staking the platform token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct StakingTier {
+    /// Minimum stake in platform token base units
+    pub min_stake: u64,
+    /// Minimum lock period in seconds the stake must be committed to (0 = unlocked stake counts)
+    pub min_lock_duration: i64,
+    /// Multiplier applied to a launch's max contribution in basis points (10000 = 1x)
+    pub allocation_multiplier: u16,
+    /// Contribution in lamports the tier may always make, whatever the launch maximum (0 = none)
+    pub guaranteed_allocation: u64,
+}
+
+impl StakingTier {
+    /// Validate a tier table: ordered by strictly increasing minimum stake, with multipliers
+    /// that never reduce the launch maximum
+    pub fn validate_tiers(tiers: &[StakingTier]) -> Result<()> {
+        if tiers.len() > MAX_STAKING_TIERS {
+            return Err(crate::errors::LaunchpadError::InvalidStakingTiers.into());
+        }
+
+        let mut previous_min_stake = 0;
+        for tier in tiers {
+            if tier.min_stake <= previous_min_stake
+                || tier.allocation_multiplier < BASIS_POINTS_MAX
+                || tier.allocation_multiplier > MAX_ALLOCATION_MULTIPLIER
+                || tier.min_lock_duration < 0
+                || tier.min_lock_duration > MAX_STAKE_LOCK_DURATION
+            {
+                return Err(crate::errors::LaunchpadError::InvalidStakingTiers.into());
+            }
+            previous_min_stake = tier.min_stake;
+        }
+
+        Ok(())
+    }
+
+    /// Largest single contribution the tier allows in a launch with `max_contribution`
+    pub fn max_contribution(&self, max_contribution: u64) -> u64 {
+        let scaled = (max_contribution as u128)
+            .saturating_mul(self.allocation_multiplier as u128)
+            / BASIS_POINTS_MAX as u128;
+        u64::try_from(scaled)
+            .unwrap_or(u64::MAX)
+            .max(self.guaranteed_allocation)
+    }
+}
+
+/// Platform tokens staked by a wallet, held in the platform stake vault
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    /// Wallet that staked the tokens
+    pub owner: Pubkey,
+    /// Tokens staked in platform token base units
+    pub amount: u64,
+    /// Longest lock period in seconds committed to since the last unstake
+    pub lock_duration: i64,
+    /// Unix timestamp until which the stake cannot be withdrawn
+    pub locked_until: i64,
+    /// Launch the stake's guaranteed allocation is reserved in (0 = none)
+    pub reserved_launch_id: u64,
+    /// Guaranteed allocation reserved in `reserved_launch_id` and not yet contributed, in lamports
+    pub reserved_allocation: u64,
+    /// Unix timestamp at which the reservation lapses
+    pub reservation_expires_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account layout version; fields added by later versions are appended after it
+    pub version: u8,
+}
+
+// Accounts are created by CPI, which caps the size of a new account
+const _: () = assert!(StakeAccount::LEN <= MAX_PERMITTED_DATA_INCREASE);
+
+impl StakeAccount {
+    /// Space needed for the account, derived from the field types
+    pub const LEN: usize = 8 + Self::INIT_SPACE; // discriminator + fields
+
+    /// Check if the stake is still locked
+    pub fn is_locked(&self, current_time: i64) -> bool {
+        current_time < self.locked_until
+    }
+
+    /// Add `amount` to the stake and lock all of it for at least `lock_duration` from now.
+    /// A lock is never shortened.
+    pub fn add_stake(&mut self, amount: u64, lock_duration: i64, current_time: i64) -> Result<()> {
+        self.amount = self.amount
+            .checked_add(amount)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        let locked_until = current_time
+            .checked_add(lock_duration)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        self.locked_until = self.locked_until.max(locked_until);
+        self.lock_duration = self.lock_duration.max(lock_duration);
+        Ok(())
+    }
+
+    /// Lamports still reserved for the stake in `launch_id`, or 0 once the reservation lapsed
+    pub fn reservation(&self, launch_id: u64, current_time: i64) -> u64 {
+        if self.reserved_launch_id == launch_id && current_time < self.reservation_expires_at {
+            self.reserved_allocation
+        } else {
+            0
+        }
+    }
+
+    /// Highest tier the stake qualifies for. Tiers with a lock period only count stake
+    /// that is still locked.
+    pub fn tier<'a>(&self, tiers: &'a [StakingTier], current_time: i64) -> Option<&'a StakingTier> {
+        tiers.iter().rev().find(|tier| {
+            self.amount >= tier.min_stake
+                && self.lock_duration >= tier.min_lock_duration
+                && (tier.min_lock_duration == 0 || self.is_locked(current_time))
+        })
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use crate::constants::*;
+
+/// Token-2022 extensions enabled on a launch mint
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default)]
+pub struct TokenExtensionsConfig {
+    /// Store name, symbol and URI on the mint behind a metadata pointer
+    pub embedded_metadata: bool,
+    /// URI of the off-chain metadata JSON for embedded metadata
+    #[max_len(MAX_URL_LENGTH)]
+    pub metadata_uri: String,
+    /// Fee charged on every transfer (in basis points, 0 disables)
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee charged on a single transfer in base units
+    pub maximum_fee: u64,
+    /// Whether tokens are soulbound to the investor's wallet
+    pub non_transferable: bool,
+}
+
+impl TokenExtensionsConfig {
+    /// Validate token extension parameters against the selected token program
+    pub fn validate(&self, is_token_2022: bool) -> Result<()> {
+        if self.is_enabled() && !is_token_2022 {
+            return Err(crate::errors::LaunchpadError::InvalidTokenExtensions.into());
+        }
+
+        if self.metadata_uri.len() > MAX_URL_LENGTH {
+            return Err(crate::errors::LaunchpadError::MetadataTooLong.into());
+        }
+        if !self.embedded_metadata && !self.metadata_uri.is_empty() {
+            return Err(crate::errors::LaunchpadError::InvalidTokenExtensions.into());
+        }
+
+        if self.transfer_fee_basis_points > MAX_TRANSFER_FEE_BASIS_POINTS {
+            return Err(crate::errors::LaunchpadError::InvalidTokenExtensions.into());
+        }
+
+        // Soulbound tokens never move, so a transfer fee would never apply
+        if self.non_transferable && self.has_transfer_fee() {
+            return Err(crate::errors::LaunchpadError::InvalidTokenExtensions.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if any extension is requested
+    pub fn is_enabled(&self) -> bool {
+        self.embedded_metadata || self.has_transfer_fee() || self.non_transferable
+    }
+
+    /// Check if the transfer fee extension is requested
+    pub fn has_transfer_fee(&self) -> bool {
+        self.transfer_fee_basis_points > 0
+    }
+
+    /// Fixed-size mint extensions initialized before the mint itself
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extension_types = Vec::new();
+        if self.embedded_metadata {
+            extension_types.push(ExtensionType::MetadataPointer);
+        }
+        if self.has_transfer_fee() {
+            extension_types.push(ExtensionType::TransferFeeConfig);
+        }
+        if self.non_transferable {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        extension_types
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// What happens to presale tokens left unsold when a launch closes below its hard cap
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Default)]
+pub enum UnsoldTokenPolicy {
+    /// Destroy the unsold tokens (minted launches simply never mint them)
+    #[default]
+    Burn,
+    /// Send the unsold tokens to the creator
+    ReturnToCreator,
+    /// Send the unsold tokens to the token account seeding the liquidity pool
+    AddToLiquidity { liquidity_account: Pubkey },
+}
+
+impl UnsoldTokenPolicy {
+    /// Validate unsold token policy parameters
+    pub fn validate(&self) -> Result<()> {
+        if let UnsoldTokenPolicy::AddToLiquidity { liquidity_account } = self {
+            if *liquidity_account == Pubkey::default() {
+                return Err(crate::errors::LaunchpadError::InvalidUnsoldTokenPolicy.into());
+            }
+        }
+        Ok(())
+    }
+}
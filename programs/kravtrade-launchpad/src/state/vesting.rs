@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 /// Vesting configuration for token releases
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct VestingConfig {
     /// Duration of cliff period in seconds
     pub cliff_duration: i64,
@@ -25,13 +25,6 @@ impl Default for VestingConfig {
 }
 
 impl VestingConfig {
-    /// Calculate space needed for vesting config
-    pub const LEN: usize = 
-        8 + // cliff_duration
-        8 + // vesting_duration
-        2 + // initial_unlock_percentage
-        1; // is_linear
-
     /// Validate vesting configuration parameters
     pub fn validate(&self) -> Result<()> {
         // Initial unlock percentage cannot exceed 100%
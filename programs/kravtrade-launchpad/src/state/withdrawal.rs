@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Configuration for withdrawing contributions during the sale
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default)]
+pub struct WithdrawalConfig {
+    /// Whether investors can withdraw contributions while the sale is active
+    pub allow_withdrawal: bool,
+    /// Penalty charged on withdrawn amounts (in basis points, 10000 = 100%)
+    pub penalty_percentage: u16,
+    /// Whether the penalty goes to the creator instead of the platform treasury
+    pub penalty_to_creator: bool,
+    /// Withdrawals close this many seconds before the presale ends
+    pub cutoff_period: i64,
+}
+
+impl WithdrawalConfig {
+    /// Validate withdrawal configuration parameters
+    pub fn validate(&self) -> Result<()> {
+        if self.penalty_percentage > BASIS_POINTS_MAX {
+            return Err(crate::errors::LaunchpadError::InvalidWithdrawalConfig.into());
+        }
+
+        if self.cutoff_period < 0 {
+            return Err(crate::errors::LaunchpadError::InvalidWithdrawalConfig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if withdrawals are open at the given time
+    pub fn is_withdrawal_open(&self, current_time: i64, end_time: i64) -> bool {
+        self.allow_withdrawal && current_time <= end_time.saturating_sub(self.cutoff_period)
+    }
+
+    /// Calculate the penalty on a withdrawal
+    pub fn calculate_penalty(&self, amount: u64) -> Result<u64> {
+        let penalty = amount
+            .checked_mul(self.penalty_percentage as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS_MAX as u64)
+            .ok_or(crate::errors::LaunchpadError::ArithmeticOverflow)?;
+        Ok(penalty)
+    }
+}
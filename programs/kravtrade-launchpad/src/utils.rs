@@ -0,0 +1,549 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, Create};
+use anchor_spl::token::{self, Burn, CloseAccount, InitializeMint2, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, MintTo, TransferChecked};
+use mpl_token_metadata::instructions::{
+    CreateMasterEditionV3CpiBuilder, CreateMetadataAccountV3CpiBuilder,
+};
+use mpl_token_metadata::types::DataV2;
+use crate::state::{
+    ContributionLedger, CreatorProfile, InvestorAccount, LaunchConfig, LedgerEntry, LedgerEntryKind,
+};
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+
+/// Move lamports out of a launch's system-owned treasury PDA
+pub fn transfer_from_treasury<'info>(
+    system_program: &Program<'info, System>,
+    treasury_account: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    launch_id: u64,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let launch_id_bytes = launch_id.to_le_bytes();
+    let treasury_seeds = &[
+        TREASURY_SEED,
+        launch_id_bytes.as_ref(),
+        &[treasury_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: treasury_account.clone(),
+                to: to.clone(),
+            },
+            &[&treasury_seeds[..]],
+        ),
+        amount,
+    )
+}
+
+/// Send tokens out of the launch vault, signed by the launch PDA.
+/// Non-transferable mints cannot leave the vault, so their tokens are minted to the recipient instead.
+pub fn release_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    token_vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    launch_config: &Account<'info, LaunchConfig>,
+    amount: u64,
+) -> Result<()> {
+    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
+    let seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if launch_config.token_extensions.non_transferable {
+        return mint_launch_tokens(token_program, token_mint, to, launch_config, amount);
+    }
+
+    // Checked transfers are required for Token-2022 mints with a transfer fee
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: token_vault.clone(),
+                mint: token_mint.clone(),
+                to: to.clone(),
+                authority: launch_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        launch_config.decimals,
+    )
+}
+
+/// Mint launch tokens, signed by the launch PDA, without exceeding the launch total supply
+pub fn mint_launch_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    launch_config: &Account<'info, LaunchConfig>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let supply = Mint::try_deserialize(&mut &token_mint.try_borrow_data()?[..])?.supply;
+    let new_supply = supply
+        .checked_add(amount)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+    if new_supply > launch_config.total_supply {
+        return Err(LaunchpadError::SupplyCapExceeded.into());
+    }
+
+    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
+    let seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: token_mint.clone(),
+                to: to.clone(),
+                authority: launch_config.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+/// Grow a program-owned account to `new_len`, topping up rent from the payer.
+/// The added bytes are zeroed, so fields appended by newer layouts read as zero values.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Deserialize an account, checking its discriminator
+pub fn load_account<T: AccountDeserialize>(account: &AccountInfo) -> Result<T> {
+    T::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
+
+/// Serialize an account back into its data, discriminator first
+pub fn store_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
+
+/// Create a program-owned PDA, even if lamports were already sent to its address
+pub fn create_program_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    create_pda_account(account, payer, system_program, signer_seeds, space, &crate::ID)
+}
+
+/// Create a PDA owned by `owner`, even if lamports were already sent to its address
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let rent_top_up = rent.saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
+
+/// Apply `update` to a creator's profile, creating it on first use and growing it to fit
+pub fn update_creator_profile<'info>(
+    profile_account: &AccountInfo<'info>,
+    creator: Pubkey,
+    profile_bump: u8,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    update: impl FnOnce(&mut CreatorProfile) -> Result<()>,
+) -> Result<()> {
+    let mut creator_profile = if profile_account.data_is_empty() {
+        let seeds = &[CREATOR_PROFILE_SEED, creator.as_ref(), &[profile_bump]];
+        create_program_account(
+            profile_account,
+            payer,
+            system_program,
+            &seeds[..],
+            CreatorProfile::space(0),
+        )?;
+        CreatorProfile::new(creator, profile_bump)
+    } else {
+        if profile_account.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        load_account::<CreatorProfile>(profile_account)?
+    };
+
+    update(&mut creator_profile)?;
+
+    resize_account(
+        profile_account,
+        payer,
+        system_program,
+        CreatorProfile::space(creator_profile.launch_ids.len()),
+    )?;
+    store_account(profile_account, &creator_profile)
+}
+
+/// Read an optional slot of a crank's `remaining_accounts` tuple. The program ID marks the
+/// slot unused, as Anchor does for omitted optional accounts.
+pub fn optional_remaining_account<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+) -> Option<&'a AccountInfo<'info>> {
+    (account.key() != crate::ID).then_some(account)
+}
+
+/// Append an entry to an investor's contribution ledger. Investors who never passed a
+/// ledger page keep none; once started, every recording instruction needs the current page.
+pub fn record_ledger_entry<'info>(
+    ledger_account: Option<&AccountInfo<'info>>,
+    investor_account: &mut InvestorAccount,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    kind: LedgerEntryKind,
+    amount: u64,
+    price: u64,
+) -> Result<()> {
+    let ledger_account = match ledger_account {
+        Some(ledger_account) => ledger_account,
+        None if investor_account.ledger_entries == 0 => return Ok(()),
+        None => return Err(LaunchpadError::ContributionLedgerMissing.into()),
+    };
+
+    // The next entry always goes to the page derived from the entry count
+    let launch_id = investor_account.launch_id;
+    let investor = investor_account.investor;
+    let page = ContributionLedger::page_for(investor_account.ledger_entries);
+    let (address, bump) = ContributionLedger::find_address(launch_id, &investor, page);
+    if ledger_account.key() != address {
+        return Err(LaunchpadError::InvalidContributionLedger.into());
+    }
+
+    let mut ledger = if ledger_account.data_is_empty() {
+        let launch_id_bytes = launch_id.to_le_bytes();
+        let page_bytes = page.to_le_bytes();
+        let seeds = &[
+            CONTRIBUTION_LEDGER_SEED,
+            launch_id_bytes.as_ref(),
+            investor.as_ref(),
+            page_bytes.as_ref(),
+            &[bump],
+        ];
+        create_program_account(
+            ledger_account,
+            payer,
+            system_program,
+            &seeds[..],
+            ContributionLedger::LEN,
+        )?;
+        ContributionLedger::new(launch_id, investor, page, bump)
+    } else {
+        if ledger_account.owner != &crate::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        load_account::<ContributionLedger>(ledger_account)?
+    };
+
+    let clock = Clock::get()?;
+    ledger.entries.push(LedgerEntry {
+        kind,
+        amount,
+        price,
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    });
+    store_account(ledger_account, &ledger)?;
+
+    investor_account.ledger_entries = investor_account.ledger_entries
+        .checked_add(1)
+        .ok_or(LaunchpadError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Mint an investor's position NFT: a PDA mint with Metaplex metadata and a master edition,
+/// so its supply is fixed at one. The launch PDA signs as mint and update authority.
+pub fn mint_position_nft<'info>(
+    launch_config: &Account<'info, LaunchConfig>,
+    investor: &AccountInfo<'info>,
+    position_mint: &AccountInfo<'info>,
+    position_token_account: &AccountInfo<'info>,
+    position_metadata: &AccountInfo<'info>,
+    position_master_edition: &AccountInfo<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<Pubkey> {
+    let launch_id_bytes = launch_config.launch_id.to_le_bytes();
+    let investor_key = investor.key();
+    let (mint_address, mint_bump) = Pubkey::find_program_address(
+        &[POSITION_MINT_SEED, launch_id_bytes.as_ref(), investor_key.as_ref()],
+        &crate::ID,
+    );
+    if position_mint.key() != mint_address {
+        return Err(LaunchpadError::InvalidPositionNft.into());
+    }
+
+    let mint_seeds = &[
+        POSITION_MINT_SEED,
+        launch_id_bytes.as_ref(),
+        investor_key.as_ref(),
+        &[mint_bump],
+    ];
+    create_pda_account(
+        position_mint,
+        investor,
+        system_program,
+        &mint_seeds[..],
+        anchor_spl::token::Mint::LEN,
+        &token::ID,
+    )?;
+
+    let launch_info = launch_config.to_account_info();
+    token::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: position_mint.clone(),
+            },
+        ),
+        0,
+        &launch_info.key(),
+        Some(&launch_info.key()),
+    )?;
+
+    associated_token::create_idempotent(CpiContext::new(
+        associated_token_program.clone(),
+        Create {
+            payer: investor.clone(),
+            associated_token: position_token_account.clone(),
+            authority: investor.clone(),
+            mint: position_mint.clone(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    let launch_seeds = &[
+        LAUNCH_SEED,
+        launch_id_bytes.as_ref(),
+        &[launch_config.bump],
+    ];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::MintTo {
+                mint: position_mint.clone(),
+                to: position_token_account.clone(),
+                authority: launch_info.clone(),
+            },
+            &[&launch_seeds[..]],
+        ),
+        1,
+    )?;
+
+    // The metadata program checks the metadata and edition addresses
+    let system_program_info = system_program.to_account_info();
+    CreateMetadataAccountV3CpiBuilder::new(token_metadata_program)
+        .metadata(position_metadata)
+        .mint(position_mint)
+        .mint_authority(&launch_info)
+        .payer(investor)
+        .update_authority(&launch_info, true)
+        .system_program(&system_program_info)
+        .data(DataV2 {
+            name: format!("{} Position", launch_config.symbol),
+            symbol: launch_config.symbol.clone(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(&[&launch_seeds[..]])?;
+
+    // The master edition takes over the mint authority, fixing the supply at one
+    CreateMasterEditionV3CpiBuilder::new(token_metadata_program)
+        .edition(position_master_edition)
+        .mint(position_mint)
+        .update_authority(&launch_info)
+        .mint_authority(&launch_info)
+        .payer(investor)
+        .metadata(position_metadata)
+        .token_program(token_program)
+        .system_program(&system_program_info)
+        .max_supply(0)
+        .invoke_signed(&[&launch_seeds[..]])?;
+
+    Ok(mint_address)
+}
+
+/// Check `signer` may act for a position: the holder of its NFT if it has one,
+/// otherwise the wallet that contributed
+pub fn authorize_position_holder(
+    investor_account: &InvestorAccount,
+    signer: &Pubkey,
+    position_token_account: Option<&Account<TokenAccount>>,
+) -> Result<()> {
+    if !investor_account.has_position_nft() {
+        if *signer != investor_account.investor {
+            return Err(LaunchpadError::Unauthorized.into());
+        }
+        return Ok(());
+    }
+
+    match position_token_account {
+        Some(token_account)
+            if token_account.mint == investor_account.position_mint
+                && token_account.owner == *signer
+                && token_account.amount == 1 => Ok(()),
+        Some(_) => Err(LaunchpadError::PositionNftNotHeld.into()),
+        None => Err(LaunchpadError::PositionNftAccountsMissing.into()),
+    }
+}
+
+/// Check a crank may pay `recipient` for a position: the holder of its NFT, shown by
+/// `position_token_account`, otherwise the wallet that contributed
+pub fn authorize_crank_recipient<'info>(
+    investor_account: &InvestorAccount,
+    recipient: &Pubkey,
+    position_token_account: Option<&'info AccountInfo<'info>>,
+) -> Result<()> {
+    if !investor_account.has_position_nft() {
+        if *recipient != investor_account.investor {
+            return Err(LaunchpadError::InvalidRemainingAccounts.into());
+        }
+        return Ok(());
+    }
+
+    let position_token_account = position_token_account
+        .map(Account::<TokenAccount>::try_from)
+        .transpose()?;
+    authorize_position_holder(investor_account, recipient, position_token_account.as_ref())
+}
+
+/// Burn the NFT of a settled position and close the holder's token account
+pub fn burn_position_nft<'info>(
+    token_program: &AccountInfo<'info>,
+    position_mint: &AccountInfo<'info>,
+    position_token_account: &AccountInfo<'info>,
+    holder: &AccountInfo<'info>,
+) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: position_mint.clone(),
+                from: position_token_account.clone(),
+                authority: holder.clone(),
+            },
+        ),
+        1,
+    )?;
+
+    token::close_account(CpiContext::new(
+        token_program.clone(),
+        CloseAccount {
+            account: position_token_account.clone(),
+            destination: holder.clone(),
+            authority: holder.clone(),
+        },
+    ))
+}
@@ -12,17 +12,22 @@ import {
   AnchorProvider,
   BN,
   web3,
+  utils,
 } from "@coral-xyz/anchor";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
+  getAccount,
 } from "@solana/spl-token";
 import {
   LaunchpadSDKConfig,
   CreateLaunchParams,
+  UpdateLaunchParams,
   InitializePlatformParams,
+  PlatformConfigUpdate,
   LaunchConfig,
   InvestorAccount,
   PlatformConfig,
@@ -30,7 +35,23 @@ import {
   LaunchStats,
   InvestorPosition,
   PlatformStats,
+  PlatformStatsAccount,
   LaunchStatus,
+  MilestoneParams,
+  MilestoneSchedule,
+  Proposal,
+  ProposalKind,
+  RefundHolding,
+  LaunchpadEvent,
+  LaunchMetadata,
+  LaunchMetadataAccount,
+  CreatorProfile,
+  LaunchRegistry,
+  RegistryBucket,
+  ContributionLedger,
+  LedgerEntry,
+  StakingTier,
+  StakeAccount,
 } from "./types";
 
 /**
@@ -45,9 +66,33 @@ export class LaunchpadSDK {
   // PDA seeds
   private static readonly PLATFORM_SEED = "platform";
   private static readonly CONFIG_SEED = "config";
+  private static readonly STATS_SEED = "stats";
   private static readonly LAUNCH_SEED = "launch";
+  private static readonly LAUNCH_METADATA_SEED = "launch_metadata";
   private static readonly INVESTOR_SEED = "investor";
   private static readonly TREASURY_SEED = "treasury";
+  private static readonly REFERRER_SEED = "referrer";
+  private static readonly INSURANCE_SEED = "insurance";
+  private static readonly INSURANCE_CLAIM_SEED = "insurance_claim";
+  private static readonly MILESTONE_SEED = "milestones";
+  private static readonly MILESTONE_VOTE_SEED = "milestone_vote";
+  private static readonly PROPOSAL_SEED = "proposal";
+  private static readonly PROPOSAL_VOTE_SEED = "proposal_vote";
+  private static readonly REFUND_HOLDING_SEED = "refund_holding";
+  private static readonly CREATOR_PROFILE_SEED = "creator_profile";
+  private static readonly LAUNCH_REGISTRY_SEED = "launch_registry";
+  private static readonly CONTRIBUTION_LEDGER_SEED = "contribution_ledger";
+  private static readonly POSITION_MINT_SEED = "position_mint";
+  private static readonly STAKE_SEED = "stake";
+  private static readonly REGISTRY_PAGE_SIZE = 64;
+  private static readonly LEDGER_PAGE_SIZE = 16;
+  private static readonly EVENT_AUTHORITY_SEED = "__event_authority";
+  private static readonly TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+  );
+
+  /** Instruction data prefix of self-invoked event CPIs */
+  private static readonly EVENT_IX_TAG = Buffer.from([0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]);
 
   constructor(
     program: Program,
@@ -95,6 +140,19 @@ export class LaunchpadSDK {
     );
   }
 
+  /**
+   * Get platform statistics PDA
+   */
+  getPlatformStatsPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.PLATFORM_SEED),
+        Buffer.from(LaunchpadSDK.STATS_SEED),
+      ],
+      this.config.programId
+    );
+  }
+
   /**
    * Get launch configuration PDA
    */
@@ -108,6 +166,200 @@ export class LaunchpadSDK {
     );
   }
 
+  /**
+   * Get creator profile PDA
+   */
+  getCreatorProfilePDA(creator: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(LaunchpadSDK.CREATOR_PROFILE_SEED), creator.toBuffer()],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get the registry page PDA of a status bucket
+   */
+  getLaunchRegistryPDA(bucket: RegistryBucket, page: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.LAUNCH_REGISTRY_SEED),
+        Buffer.from([bucket]),
+        page.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get the registry page PDA listing a launch in a status bucket
+   */
+  getLaunchRegistryPDAForLaunch(bucket: RegistryBucket, launchId: BN): [PublicKey, number] {
+    return this.getLaunchRegistryPDA(bucket, launchId.divn(LaunchpadSDK.REGISTRY_PAGE_SIZE));
+  }
+
+  /**
+   * Get the position NFT mint PDA of an investor's position
+   */
+  getPositionMintPDA(launchId: BN, investor: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.POSITION_MINT_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+        investor.toBuffer(),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get a wallet's stake account PDA
+   */
+  getStakeAccountPDA(owner: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(LaunchpadSDK.STAKE_SEED), owner.toBuffer()],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get the Metaplex metadata and master edition PDAs of a position NFT
+   */
+  getPositionNftMetadataPDAs(positionMint: PublicKey): {
+    metadata: PublicKey;
+    masterEdition: PublicKey;
+  } {
+    const programId = LaunchpadSDK.TOKEN_METADATA_PROGRAM_ID;
+    const [metadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), programId.toBuffer(), positionMint.toBuffer()],
+      programId
+    );
+    const [masterEdition] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), programId.toBuffer(), positionMint.toBuffer(), Buffer.from("edition")],
+      programId
+    );
+    return { metadata, masterEdition };
+  }
+
+  /**
+   * Get the position NFT mint and the holder's NFT token account of a position,
+   * or nulls if the position is not represented by an NFT
+   */
+  async getPositionNftAccounts(
+    launchId: BN,
+    positionOwner: PublicKey,
+    holder: PublicKey
+  ): Promise<{ positionMint: PublicKey | null; positionTokenAccount: PublicKey | null }> {
+    const investorAccount = await this.getInvestorAccount(launchId, positionOwner);
+    if (!investorAccount || investorAccount.positionMint.equals(PublicKey.default)) {
+      return { positionMint: null, positionTokenAccount: null };
+    }
+    return {
+      positionMint: investorAccount.positionMint,
+      positionTokenAccount: await getAssociatedTokenAddress(
+        investorAccount.positionMint,
+        holder
+      ),
+    };
+  }
+
+  /**
+   * Find the current holder of a position NFT and their token account holding it
+   */
+  async getPositionNftHolder(
+    positionMint: PublicKey
+  ): Promise<{ holder: PublicKey; positionTokenAccount: PublicKey } | null> {
+    const largestAccounts = await this.connection.getTokenLargestAccounts(positionMint);
+    const holding = largestAccounts.value.find((account) => account.uiAmount === 1);
+    if (!holding) {
+      return null;
+    }
+    const tokenAccount = await getAccount(this.connection, holding.address);
+    return { holder: tokenAccount.owner, positionTokenAccount: holding.address };
+  }
+
+  /**
+   * Get the recipient a crank pays for a position and the accounts it passes for it:
+   * the NFT holder for NFT positions, otherwise the wallet that contributed. The
+   * program ID stands in for a ledger page or NFT token account the position lacks.
+   */
+  async getCrankRecipientAccounts(
+    launchId: BN,
+    investor: PublicKey
+  ): Promise<{ recipient: PublicKey; contributionLedger: PublicKey; positionTokenAccount: PublicKey }> {
+    const investorAccount = await this.getInvestorAccount(launchId, investor);
+    const contributionLedger =
+      (await this.getNextLedgerPagePDA(launchId, investor)) ?? this.program.programId;
+    if (!investorAccount || investorAccount.positionMint.equals(PublicKey.default)) {
+      return { recipient: investor, contributionLedger, positionTokenAccount: this.program.programId };
+    }
+
+    const holding = await this.getPositionNftHolder(investorAccount.positionMint);
+    if (!holding) {
+      throw new Error("Position NFT holder not found");
+    }
+    return {
+      recipient: holding.holder,
+      contributionLedger,
+      positionTokenAccount: holding.positionTokenAccount,
+    };
+  }
+
+  /**
+   * Get a contribution ledger page PDA
+   */
+  getContributionLedgerPDA(launchId: BN, investor: PublicKey, page: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.CONTRIBUTION_LEDGER_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+        investor.toBuffer(),
+        page.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get the ledger page the investor's next entry goes to, or null if no ledger is kept.
+   * Pass `startLedger` to begin a ledger with the next entry.
+   */
+  async getNextLedgerPagePDA(
+    launchId: BN,
+    investor: PublicKey,
+    startLedger = false
+  ): Promise<PublicKey | null> {
+    const investorAccount = await this.getInvestorAccount(launchId, investor);
+    const ledgerEntries = investorAccount?.ledgerEntries ?? new BN(0);
+    if (ledgerEntries.isZero() && !startLedger) {
+      return null;
+    }
+    return this.getContributionLedgerPDA(
+      launchId,
+      investor,
+      ledgerEntries.divn(LaunchpadSDK.LEDGER_PAGE_SIZE)
+    )[0];
+  }
+
+  /**
+   * Get the ID the program will assign to the next created launch
+   */
+  getNextLaunchId(platformConfig: PlatformConfig): BN {
+    return platformConfig.lastLaunchId.addn(1);
+  }
+
+  /**
+   * Get launch metadata PDA
+   */
+  getLaunchMetadataPDA(launchId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.LAUNCH_METADATA_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
   /**
    * Get investor account PDA
    */
@@ -135,6 +387,126 @@ export class LaunchpadSDK {
     );
   }
 
+  /**
+   * Get referrer account PDA
+   */
+  getReferrerPDA(launchId: BN, referrer: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.REFERRER_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+        referrer.toBuffer(),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get insurance fund PDA
+   */
+  getInsuranceFundPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(LaunchpadSDK.INSURANCE_SEED)],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get insurance claim PDA for a flagged launch
+   */
+  getInsuranceClaimPDA(launchId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.INSURANCE_CLAIM_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get milestone schedule PDA
+   */
+  getMilestoneSchedulePDA(launchId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.MILESTONE_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get milestone vote receipt PDA
+   */
+  getMilestoneVotePDA(
+    launchId: BN,
+    milestoneIndex: number,
+    investor: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.MILESTONE_VOTE_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+        Buffer.from([milestoneIndex]),
+        investor.toBuffer(),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get governance proposal PDA
+   */
+  getProposalPDA(launchId: BN, proposalId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.PROPOSAL_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+        proposalId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get proposal vote receipt PDA
+   */
+  getProposalVotePDA(proposal: PublicKey, investor: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.PROPOSAL_VOTE_SEED),
+        proposal.toBuffer(),
+        investor.toBuffer(),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get refund holding PDA
+   */
+  getRefundHoldingPDA(launchId: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LaunchpadSDK.REFUND_HOLDING_SEED),
+        launchId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.config.programId
+    );
+  }
+
+  /**
+   * Get event authority PDA used to sign event CPIs
+   */
+  getEventAuthorityPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(LaunchpadSDK.EVENT_AUTHORITY_SEED)],
+      this.config.programId
+    );
+  }
+
   // ============================================================================
   // Platform Management Methods
   // ============================================================================
@@ -158,6 +530,7 @@ export class LaunchpadSDK {
         )
         .accounts({
           platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
           admin: admin.publicKey,
           treasury: params.treasury,
           systemProgram: SystemProgram.programId,
@@ -179,25 +552,71 @@ export class LaunchpadSDK {
    * Update platform configuration
    */
   async updatePlatformConfig(
-    platformFeePercentage?: number,
-    minLaunchDuration?: BN,
-    maxLaunchDuration?: BN,
-    minSoftCap?: BN,
+    update: PlatformConfigUpdate,
     admin: Keypair
   ): Promise<TransactionResult> {
     try {
       const [platformConfigPDA] = this.getPlatformConfigPDA();
 
       const tx = await this.program.methods
-        .updatePlatformConfig(
-          platformFeePercentage || null,
-          minLaunchDuration || null,
-          maxLaunchDuration || null,
-          minSoftCap || null
-        )
+        .updatePlatformConfig({
+          platformFeePercentage: update.platformFeePercentage ?? null,
+          minLaunchDuration: update.minLaunchDuration ?? null,
+          maxLaunchDuration: update.maxLaunchDuration ?? null,
+          minSoftCap: update.minSoftCap ?? null,
+          listingFee: update.listingFee ?? null,
+          creatorBondAmount: update.creatorBondAmount ?? null,
+          referralFeeShare: update.referralFeeShare ?? null,
+          insuranceFeeShare: update.insuranceFeeShare ?? null,
+          milestoneThreshold: update.milestoneThreshold ?? null,
+          refundWindow: update.refundWindow ?? null,
+          lastLaunchId: update.lastLaunchId ?? null,
+        })
+        .accounts({
+          platformConfig: platformConfigPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Set the platform stake mint and replace the allocation tiers
+   */
+  async configureStaking(
+    stakeMint: PublicKey,
+    tiers: StakingTier[],
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const tokenProgram = await this.getMintTokenProgram(stakeMint);
+      const stakeVault = await getAssociatedTokenAddress(
+        stakeMint,
+        platformConfigPDA,
+        true,
+        tokenProgram
+      );
+
+      const tx = await this.program.methods
+        .configureStaking(tiers)
         .accounts({
           platformConfig: platformConfigPDA,
+          stakeMint: stakeMint,
+          stakeVault: stakeVault,
           admin: admin.publicKey,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
@@ -226,17 +645,26 @@ export class LaunchpadSDK {
   ): Promise<TransactionResult> {
     try {
       const [platformConfigPDA] = this.getPlatformConfigPDA();
-      const [launchConfigPDA] = this.getLaunchConfigPDA(params.launchId);
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not initialized");
+      }
+
+      // The program assigns the next launch ID and derives the launch accounts from it
+      const launchId = this.getNextLaunchId(platformConfig);
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
       
+      // Token-2022 is required for mint extensions
+      const tokenProgram = params.useToken2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
       const tokenVault = await getAssociatedTokenAddress(
         tokenMint.publicKey,
         launchConfigPDA,
-        true
+        true,
+        tokenProgram
       );
 
       const tx = await this.program.methods
         .createLaunch(
-          params.launchId,
           params.name,
           params.symbol,
           params.decimals,
@@ -249,15 +677,27 @@ export class LaunchpadSDK {
           params.startTime,
           params.endTime,
           params.vestingConfig,
-          params.metadata
+          params.governanceConfig,
+          params.withdrawalConfig,
+          params.metadata,
+          params.tokenExtensions,
+          params.mintAuthorityPolicy,
+          params.unsoldTokenPolicy,
+          params.positionTransferPolicy ?? { disabled: {} },
+          params.positionNfts ?? false
         )
         .accounts({
           launchConfig: launchConfigPDA,
+          launchMetadata: this.getLaunchMetadataPDA(launchId)[0],
+          creatorProfile: this.getCreatorProfilePDA(creator.publicKey)[0],
+          pendingRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Pending, launchId)[0],
           platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          platformTreasury: platformConfig.treasury,
           tokenMint: tokenMint.publicKey,
           tokenVault: tokenVault,
           creator: creator.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgram: tokenProgram,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -265,7 +705,7 @@ export class LaunchpadSDK {
         .signers([creator, tokenMint])
         .rpc();
 
-      return { signature: tx, success: true };
+      return { signature: tx, success: true, launchId };
     } catch (error) {
       return {
         signature: "",
@@ -276,25 +716,1366 @@ export class LaunchpadSDK {
   }
 
   /**
-   * Approve a launch (admin only)
+   * Create a launch that sells a deposited supply of an existing mint
    */
-  async approveLaunch(
-    launchId: BN,
-    admin: Keypair
+  async createLaunchFromExistingMint(
+    params: CreateLaunchParams,
+    creator: Keypair,
+    tokenMint: PublicKey
   ): Promise<TransactionResult> {
     try {
-      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
       const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not initialized");
+      }
 
-      const tx = await this.program.methods
-        .approveLaunch(launchId)
-        .accounts({
-          launchConfig: launchConfigPDA,
-          platformConfig: platformConfigPDA,
-          admin: admin.publicKey,
-        })
-        .signers([admin])
-        .rpc();
+      // The program assigns the next launch ID and derives the launch accounts from it
+      const launchId = this.getNextLaunchId(platformConfig);
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+
+      // The vault must live under the program that owns the mint
+      const mintInfo = await this.connection.getAccountInfo(tokenMint);
+      if (!mintInfo) {
+        throw new Error("Mint not found");
+      }
+      const tokenProgram = mintInfo.owner;
+      const tokenVault = await getAssociatedTokenAddress(
+        tokenMint,
+        launchConfigPDA,
+        true,
+        tokenProgram
+      );
+
+      const tx = await this.program.methods
+        .createLaunch(
+          params.name,
+          params.symbol,
+          params.decimals,
+          params.totalSupply,
+          params.presalePrice,
+          params.minContribution,
+          params.maxContribution,
+          params.softCap,
+          params.hardCap,
+          params.startTime,
+          params.endTime,
+          params.vestingConfig,
+          params.governanceConfig,
+          params.withdrawalConfig,
+          params.metadata,
+          params.tokenExtensions,
+          params.mintAuthorityPolicy,
+          params.unsoldTokenPolicy,
+          params.positionTransferPolicy ?? { disabled: {} },
+          params.positionNfts ?? false
+        )
+        .accounts({
+          launchConfig: launchConfigPDA,
+          launchMetadata: this.getLaunchMetadataPDA(launchId)[0],
+          creatorProfile: this.getCreatorProfilePDA(creator.publicKey)[0],
+          pendingRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Pending, launchId)[0],
+          platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          platformTreasury: platformConfig.treasury,
+          tokenMint: tokenMint,
+          tokenVault: tokenVault,
+          creator: creator.publicKey,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true, launchId };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Deposit sale inventory into the vault of an existing-mint launch
+   */
+  async fundLaunch(
+    launchId: BN,
+    amount: BN,
+    creator: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not initialized");
+      }
+
+      const tokenVault = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        launchConfigPDA,
+        true,
+        launchConfig.tokenProgram
+      );
+      const creatorTokenAccount = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        creator.publicKey,
+        false,
+        launchConfig.tokenProgram
+      );
+
+      const tx = await this.program.methods
+        .fundLaunch(launchId, amount)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          tokenMint: launchConfig.tokenMint,
+          tokenVault: tokenVault,
+          creatorTokenAccount: creatorTokenAccount,
+          creator: creator.publicKey,
+          tokenProgram: launchConfig.tokenProgram,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Edit prices, caps, times, vesting and metadata of a launch awaiting review.
+   * A configured milestone schedule is closed and must be configured again.
+   */
+  async updateLaunch(
+    params: UpdateLaunchParams,
+    creator: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(params.launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+
+      const launchConfig = await this.getLaunchConfig(params.launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+
+      const tx = await this.program.methods
+        .updateLaunch(
+          params.launchId,
+          params.presalePrice,
+          params.minContribution,
+          params.maxContribution,
+          params.softCap,
+          params.hardCap,
+          params.startTime,
+          params.endTime,
+          params.vestingConfig,
+          params.metadata
+        )
+        .accounts({
+          launchConfig: launchConfigPDA,
+          launchMetadata: this.getLaunchMetadataPDA(params.launchId)[0],
+          milestoneSchedule: launchConfig.usesMilestones
+            ? this.getMilestoneSchedulePDA(params.launchId)[0]
+            : null,
+          platformConfig: platformConfigPDA,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Update the metadata of a pending launch, or of a launch the admin has unlocked
+   */
+  async updateLaunchMetadata(
+    launchId: BN,
+    metadata: LaunchMetadata,
+    creator: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [launchMetadataPDA] = this.getLaunchMetadataPDA(launchId);
+
+      const tx = await this.program.methods
+        .updateLaunchMetadata(launchId, metadata)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          launchMetadata: launchMetadataPDA,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Allow or lock metadata updates after approval (admin only)
+   */
+  async allowMetadataUpdates(
+    launchId: BN,
+    allowed: boolean,
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchMetadataPDA] = this.getLaunchMetadataPDA(launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+
+      const tx = await this.program.methods
+        .allowMetadataUpdates(launchId, allowed)
+        .accounts({
+          launchMetadata: launchMetadataPDA,
+          platformConfig: platformConfigPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Approve a launch (admin only)
+   */
+  async approveLaunch(
+    launchId: BN,
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+
+      const tx = await this.program.methods
+        .approveLaunch(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          platformConfig: platformConfigPDA,
+          pendingRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Pending, launchId)[0],
+          activeRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Active, launchId)[0],
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Reject a launch (admin only)
+   */
+  async rejectLaunch(
+    launchId: BN,
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not initialized");
+      }
+
+      const tx = await this.program.methods
+        .rejectLaunch(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          platformTreasury: platformConfig.treasury,
+          pendingRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Pending, launchId)[0],
+          endedRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Ended, launchId)[0],
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Cancel an approved launch (admin only)
+   */
+  async cancelLaunch(
+    launchId: BN,
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not initialized");
+      }
+
+      const tx = await this.program.methods
+        .cancelLaunch(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          platformTreasury: platformConfig.treasury,
+          activeRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Active, launchId)[0],
+          endedRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Ended, launchId)[0],
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  // ============================================================================
+  // Investor Methods
+  // ============================================================================
+
+  /**
+   * Contribute to a launch
+   */
+  async contribute(
+    launchId: BN,
+    amount: BN,
+    investor: Keypair,
+    referrer?: PublicKey,
+    startLedger = false
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor.publicKey);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+      const referrerAccountPDA = referrer
+        ? this.getReferrerPDA(launchId, referrer)[0]
+        : null;
+      const contributionLedger = await this.getNextLedgerPagePDA(
+        launchId,
+        investor.publicKey,
+        startLedger
+      );
+
+      // A stake raises the contribution limit to the investor's tier
+      const stakeAccount = await this.getStakeAccount(investor.publicKey);
+      const stakeAccountPDA = stakeAccount
+        ? this.getStakeAccountPDA(investor.publicKey)[0]
+        : null;
+
+      // The first contribution to a launch with position NFTs mints the investor's NFT
+      const launchConfig = await this.getLaunchConfig(launchId);
+      const investorAccount = await this.getInvestorAccount(launchId, investor.publicKey);
+      const mintsPositionNft =
+        !!launchConfig?.positionNfts &&
+        (!investorAccount || investorAccount.positionMint.equals(PublicKey.default));
+      const [positionMintPDA] = this.getPositionMintPDA(launchId, investor.publicKey);
+      const positionNftPDAs = this.getPositionNftMetadataPDAs(positionMintPDA);
+      const positionTokenAccount = await getAssociatedTokenAddress(
+        positionMintPDA,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .contribute(amount)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          treasuryAccount: treasuryPDA,
+          referrerAccount: referrerAccountPDA,
+          stakeAccount: stakeAccountPDA,
+          contributionLedger: contributionLedger,
+          positionMint: mintsPositionNft ? positionMintPDA : null,
+          positionTokenAccount: mintsPositionNft ? positionTokenAccount : null,
+          positionMetadata: mintsPositionNft ? positionNftPDAs.metadata : null,
+          positionMasterEdition: mintsPositionNft ? positionNftPDAs.masterEdition : null,
+          tokenMetadataProgram: mintsPositionNft ? LaunchpadSDK.TOKEN_METADATA_PROGRAM_ID : null,
+          positionTokenProgram: mintsPositionNft ? TOKEN_PROGRAM_ID : null,
+          associatedTokenProgram: mintsPositionNft ? ASSOCIATED_TOKEN_PROGRAM_ID : null,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Withdraw part or all of a contribution while the sale is live
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async withdrawContribution(
+    launchId: BN,
+    amount: BN,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      const platformConfig = await this.getPlatformConfig();
+      if (!launchConfig || !platformConfig) {
+        throw new Error("Launch not found");
+      }
+
+      // The penalty goes to the creator payout address or the platform treasury
+      const penaltyRecipient = launchConfig.withdrawalConfig.penaltyToCreator
+        ? launchConfig.payoutAddress
+        : platformConfig.treasury;
+
+      const contributionLedger = await this.getNextLedgerPagePDA(launchId, positionOwner);
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      // Withdrawing referred contributions reverses the referrer's attribution
+      const investorAccount = await this.getInvestorAccount(launchId, positionOwner);
+      const referrerAccountPDA =
+        investorAccount && !investorAccount.referredAmount.isZero()
+          ? this.getReferrerPDA(launchId, investorAccount.referrer)[0]
+          : null;
+
+      const tx = await this.program.methods
+        .withdrawContribution(launchId, amount)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          platformConfig: platformConfigPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          treasuryAccount: treasuryPDA,
+          penaltyRecipient: penaltyRecipient,
+          referrerAccount: referrerAccountPDA,
+          contributionLedger: contributionLedger,
+          positionTokenAccount: positionTokenAccount,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Stake platform tokens, locking the whole stake for at least `lockDuration` seconds
+   */
+  async stake(
+    amount: BN,
+    lockDuration: BN,
+    staker: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [stakeAccountPDA] = this.getStakeAccountPDA(staker.publicKey);
+
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not found");
+      }
+
+      const stakeMint = platformConfig.stakeMint;
+      const tokenProgram = await this.getMintTokenProgram(stakeMint);
+      const stakeVault = await getAssociatedTokenAddress(
+        stakeMint,
+        platformConfigPDA,
+        true,
+        tokenProgram
+      );
+      const stakerTokenAccount = await getAssociatedTokenAddress(
+        stakeMint,
+        staker.publicKey,
+        false,
+        tokenProgram
+      );
+
+      const tx = await this.program.methods
+        .stake(amount, lockDuration)
+        .accounts({
+          platformConfig: platformConfigPDA,
+          stakeAccount: stakeAccountPDA,
+          stakeMint: stakeMint,
+          stakeVault: stakeVault,
+          stakerTokenAccount: stakerTokenAccount,
+          staker: staker.publicKey,
+          tokenProgram: tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([staker])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Withdraw staked platform tokens once the lock has expired
+   */
+  async unstake(amount: BN, staker: Keypair): Promise<TransactionResult> {
+    try {
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [stakeAccountPDA] = this.getStakeAccountPDA(staker.publicKey);
+
+      const platformConfig = await this.getPlatformConfig();
+      if (!platformConfig) {
+        throw new Error("Platform not found");
+      }
+
+      const stakeMint = platformConfig.stakeMint;
+      const tokenProgram = await this.getMintTokenProgram(stakeMint);
+      const stakeVault = await getAssociatedTokenAddress(
+        stakeMint,
+        platformConfigPDA,
+        true,
+        tokenProgram
+      );
+      const stakerTokenAccount = await getAssociatedTokenAddress(
+        stakeMint,
+        staker.publicKey,
+        false,
+        tokenProgram
+      );
+
+      const tx = await this.program.methods
+        .unstake(amount)
+        .accounts({
+          platformConfig: platformConfigPDA,
+          stakeAccount: stakeAccountPDA,
+          stakeMint: stakeMint,
+          stakeVault: stakeVault,
+          stakerTokenAccount: stakerTokenAccount,
+          staker: staker.publicKey,
+          tokenProgram: tokenProgram,
+        })
+        .signers([staker])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Reserve the guaranteed allocation of the investor's staking tier in a launch
+   */
+  async reserveAllocation(launchId: BN, investor: Keypair): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor.publicKey);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [stakeAccountPDA] = this.getStakeAccountPDA(investor.publicKey);
+
+      const tx = await this.program.methods
+        .reserveAllocation(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          platformConfig: platformConfigPDA,
+          stakeAccount: stakeAccountPDA,
+          investor: investor.publicKey,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Move all or part of a position to another wallet, if the launch allows it
+   */
+  async transferPosition(
+    launchId: BN,
+    amount: BN,
+    recipient: PublicKey,
+    investor: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor.publicKey);
+      const [recipientAccountPDA] = this.getInvestorAccountPDA(launchId, recipient);
+
+      const contributionLedger = await this.getNextLedgerPagePDA(launchId, investor.publicKey);
+      const recipientLedger = await this.getNextLedgerPagePDA(launchId, recipient);
+
+      // A stake raises the recipient's cap during the sale to its tier
+      const recipientStake = await this.getStakeAccount(recipient);
+      const recipientStakeAccountPDA = recipientStake
+        ? this.getStakeAccountPDA(recipient)[0]
+        : null;
+
+      const tx = await this.program.methods
+        .transferPosition(launchId, amount)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          recipientAccount: recipientAccountPDA,
+          recipient: recipient,
+          platformConfig: this.getPlatformConfigPDA()[0],
+          platformStats: this.getPlatformStatsPDA()[0],
+          recipientStakeAccount: recipientStakeAccountPDA,
+          contributionLedger: contributionLedger,
+          recipientLedger: recipientLedger,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Claim vested tokens
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async claimTokens(
+    launchId: BN,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      
+      // Get launch config to find token mint
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+
+      const tokenVault = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        launchConfigPDA,
+        true,
+        launchConfig.tokenProgram
+      );
+
+      const investorTokenAccount = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        investor.publicKey,
+        false,
+        launchConfig.tokenProgram
+      );
+
+      // Create associated token account if it doesn't exist
+      const accountInfo = await this.connection.getAccountInfo(investorTokenAccount);
+      const instructions = [];
+      
+      if (!accountInfo) {
+        instructions.push(
+          createAssociatedTokenAccountInstruction(
+            investor.publicKey,
+            investorTokenAccount,
+            investor.publicKey,
+            launchConfig.tokenMint,
+            launchConfig.tokenProgram
+          )
+        );
+      }
+
+      const contributionLedger = await this.getNextLedgerPagePDA(launchId, positionOwner);
+      const { positionMint, positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .claimTokens(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          tokenMint: launchConfig.tokenMint,
+          tokenVault: tokenVault,
+          investorTokenAccount: investorTokenAccount,
+          platformStats: this.getPlatformStatsPDA()[0],
+          contributionLedger: contributionLedger,
+          positionTokenAccount: positionTokenAccount,
+          positionMint: positionMint,
+          positionTokenProgram: positionMint ? TOKEN_PROGRAM_ID : null,
+          investor: investor.publicKey,
+          tokenProgram: launchConfig.tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions(instructions)
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Push claimable tokens to a batch of investors of a successful launch
+   */
+  async distributeTokens(
+    launchId: BN,
+    investors: PublicKey[],
+    distributor: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+
+      const tokenVault = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        launchConfigPDA,
+        true,
+        launchConfig.tokenProgram
+      );
+
+      // Each investor is passed as an (investor account, recipient, recipient token account,
+      // ledger page, position NFT token account) tuple
+      const remainingAccounts = [];
+      for (const investor of investors) {
+        const { recipient, contributionLedger, positionTokenAccount } =
+          await this.getCrankRecipientAccounts(launchId, investor);
+        const recipientTokenAccount = await getAssociatedTokenAddress(
+          launchConfig.tokenMint,
+          recipient,
+          false,
+          launchConfig.tokenProgram
+        );
+        remainingAccounts.push(
+          {
+            pubkey: this.getInvestorAccountPDA(launchId, investor)[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: recipient, isSigner: false, isWritable: true },
+          { pubkey: recipientTokenAccount, isSigner: false, isWritable: true },
+          {
+            pubkey: contributionLedger,
+            isSigner: false,
+            isWritable: !contributionLedger.equals(this.program.programId),
+          },
+          { pubkey: positionTokenAccount, isSigner: false, isWritable: false }
+        );
+      }
+
+      const tx = await this.program.methods
+        .distributeTokens(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          tokenMint: launchConfig.tokenMint,
+          tokenVault: tokenVault,
+          platformStats: this.getPlatformStatsPDA()[0],
+          distributor: distributor.publicKey,
+          tokenProgram: launchConfig.tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([distributor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Push refunds to a batch of investors of a failed launch
+   */
+  async processRefunds(
+    launchId: BN,
+    investors: PublicKey[],
+    processor: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
+      // Each investor is passed as an (investor account, recipient, ledger page,
+      // position NFT token account) tuple
+      const remainingAccounts = [];
+      for (const investor of investors) {
+        const { recipient, contributionLedger, positionTokenAccount } =
+          await this.getCrankRecipientAccounts(launchId, investor);
+        remainingAccounts.push(
+          {
+            pubkey: this.getInvestorAccountPDA(launchId, investor)[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: recipient, isSigner: false, isWritable: true },
+          {
+            pubkey: contributionLedger,
+            isSigner: false,
+            isWritable: !contributionLedger.equals(this.program.programId),
+          },
+          { pubkey: positionTokenAccount, isSigner: false, isWritable: false }
+        );
+      }
+
+      const tx = await this.program.methods
+        .processRefunds(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          treasuryAccount: treasuryPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          processor: processor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([processor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Sweep unclaimed refunds of a failed launch after the refund window (admin only)
+   */
+  async sweepRefunds(
+    launchId: BN,
+    admin: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+      const [refundHoldingPDA] = this.getRefundHoldingPDA(launchId);
+
+      const tx = await this.program.methods
+        .sweepRefunds(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          platformConfig: platformConfigPDA,
+          treasuryAccount: treasuryPDA,
+          refundHolding: refundHoldingPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Register as a referrer for a launch
+   */
+  async registerReferrer(
+    launchId: BN,
+    referrer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [referrerAccountPDA] = this.getReferrerPDA(launchId, referrer.publicKey);
+
+      const tx = await this.program.methods
+        .registerReferrer(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          referrerAccount: referrerAccountPDA,
+          referrer: referrer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([referrer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Claim referral rewards from a successful launch
+   */
+  async claimReferralRewards(
+    launchId: BN,
+    referrer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [referrerAccountPDA] = this.getReferrerPDA(launchId, referrer.publicKey);
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
+      const tx = await this.program.methods
+        .claimReferralRewards(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          referrerAccount: referrerAccountPDA,
+          treasuryAccount: treasuryPDA,
+          referrer: referrer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([referrer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Claim insurance compensation for a flagged launch
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async claimInsurance(
+    launchId: BN,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [insuranceClaimPDA] = this.getInsuranceClaimPDA(launchId);
+      const [insuranceFundPDA] = this.getInsuranceFundPDA();
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .claimInsurance(launchId)
+        .accounts({
+          insuranceClaim: insuranceClaimPDA,
+          insuranceFund: insuranceFundPDA,
+          investorAccount: investorAccountPDA,
+          positionTokenAccount: positionTokenAccount,
+          investor: investor.publicKey,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Configure the milestone schedule for a pending launch
+   */
+  async configureMilestones(
+    launchId: BN,
+    milestones: MilestoneParams[],
+    creator: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+
+      const tx = await this.program.methods
+        .configureMilestones(launchId, milestones)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          milestoneSchedule: milestoneSchedulePDA,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Open an investor vote on releasing a milestone
+   */
+  async startMilestoneVote(
+    launchId: BN,
+    milestoneIndex: number,
+    creator: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+
+      const tx = await this.program.methods
+        .startMilestoneVote(launchId, milestoneIndex)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          milestoneSchedule: milestoneSchedulePDA,
+          creator: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Vote on a milestone release
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async voteMilestone(
+    launchId: BN,
+    milestoneIndex: number,
+    approve: boolean,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const [milestoneVotePDA] = this.getMilestoneVotePDA(
+        launchId,
+        milestoneIndex,
+        positionOwner
+      );
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .voteMilestone(launchId, milestoneIndex, approve)
+        .accounts({
+          milestoneSchedule: milestoneSchedulePDA,
+          investorAccount: investorAccountPDA,
+          milestoneVote: milestoneVotePDA,
+          positionTokenAccount: positionTokenAccount,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Claim a refund of unreleased escrow after a rejected milestone
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async claimMilestoneRefund(
+    launchId: BN,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+      const contributionLedger = await this.getNextLedgerPagePDA(launchId, positionOwner);
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .claimMilestoneRefund(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          milestoneSchedule: milestoneSchedulePDA,
+          investorAccount: investorAccountPDA,
+          treasuryAccount: treasuryPDA,
+          platformStats: this.getPlatformStatsPDA()[0],
+          contributionLedger: contributionLedger,
+          positionTokenAccount: positionTokenAccount,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Resolve a milestone vote after the voting period
+   */
+  async releaseMilestone(
+    launchId: BN,
+    milestoneIndex: number,
+    payer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+
+      const tx = await this.program.methods
+        .releaseMilestone(launchId, milestoneIndex)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          milestoneSchedule: milestoneSchedulePDA,
+          treasuryAccount: treasuryPDA,
+          payoutAccount: launchConfig.payoutAddress,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Create a governance proposal for a launch
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async createProposal(
+    launchId: BN,
+    kind: ProposalKind,
+    proposer: Keypair,
+    positionOwner: PublicKey = proposer.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        proposer.publicKey
+      );
+
+      // Proposals are seeded by the launch's next proposal ID
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+      const [proposalPDA] = this.getProposalPDA(launchId, launchConfig.proposalCount);
+
+      const tx = await this.program.methods
+        .createProposal(launchId, kind)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          investorAccount: investorAccountPDA,
+          proposal: proposalPDA,
+          positionTokenAccount: positionTokenAccount,
+          proposer: proposer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Vote on a governance proposal
+   * For a position NFT bought from another wallet, pass that wallet as `positionOwner`.
+   */
+  async castVote(
+    launchId: BN,
+    proposalId: BN,
+    approve: boolean,
+    investor: Keypair,
+    positionOwner: PublicKey = investor.publicKey
+  ): Promise<TransactionResult> {
+    try {
+      const [proposalPDA] = this.getProposalPDA(launchId, proposalId);
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, positionOwner);
+      const [proposalVotePDA] = this.getProposalVotePDA(proposalPDA, positionOwner);
+      const { positionTokenAccount } = await this.getPositionNftAccounts(
+        launchId,
+        positionOwner,
+        investor.publicKey
+      );
+
+      const tx = await this.program.methods
+        .castVote(launchId, proposalId, approve)
+        .accounts({
+          proposal: proposalPDA,
+          investorAccount: investorAccountPDA,
+          proposalVote: proposalVotePDA,
+          positionTokenAccount: positionTokenAccount,
+          investor: investor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor])
+        .rpc();
 
       return { signature: tx, success: true };
     } catch (error) {
@@ -307,24 +2088,34 @@ export class LaunchpadSDK {
   }
 
   /**
-   * Reject a launch (admin only)
+   * Tally a governance proposal and apply it if it passed
    */
-  async rejectLaunch(
+  async executeProposal(
     launchId: BN,
-    admin: Keypair
+    proposalId: BN,
+    executor: Keypair
   ): Promise<TransactionResult> {
     try {
       const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
-      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [proposalPDA] = this.getProposalPDA(launchId, proposalId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
+      const milestoneSchedulePDA = launchConfig.usesMilestones
+        ? this.getMilestoneSchedulePDA(launchId)[0]
+        : null;
 
       const tx = await this.program.methods
-        .rejectLaunch(launchId)
+        .executeProposal(launchId, proposalId)
         .accounts({
           launchConfig: launchConfigPDA,
-          platformConfig: platformConfigPDA,
-          admin: admin.publicKey,
+          proposal: proposalPDA,
+          milestoneSchedule: milestoneSchedulePDA,
+          executor: executor.publicKey,
         })
-        .signers([admin])
+        .signers([executor])
         .rpc();
 
       return { signature: tx, success: true };
@@ -337,33 +2128,32 @@ export class LaunchpadSDK {
     }
   }
 
-  // ============================================================================
-  // Investor Methods
-  // ============================================================================
-
   /**
-   * Contribute to a launch
+   * Close a settled investor account and reclaim its rent
    */
-  async contribute(
+  async closeInvestorAccount(
     launchId: BN,
-    amount: BN,
     investor: Keypair
   ): Promise<TransactionResult> {
     try {
       const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
       const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor.publicKey);
-      const [platformConfigPDA] = this.getPlatformConfigPDA();
-      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
+      const launchConfig = await this.getLaunchConfig(launchId);
+      if (!launchConfig) {
+        throw new Error("Launch not found");
+      }
 
       const tx = await this.program.methods
-        .contribute(amount)
+        .closeInvestorAccount(launchId)
         .accounts({
           launchConfig: launchConfigPDA,
           investorAccount: investorAccountPDA,
-          platformConfig: platformConfigPDA,
-          treasuryAccount: treasuryPDA,
+          milestoneSchedule: launchConfig.usesMilestones
+            ? this.getMilestoneSchedulePDA(launchId)[0]
+            : null,
+          insuranceClaim: this.getInsuranceClaimPDA(launchId)[0],
           investor: investor.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .signers([investor])
         .rpc();
@@ -379,17 +2169,16 @@ export class LaunchpadSDK {
   }
 
   /**
-   * Claim vested tokens
+   * Close a concluded launch and reclaim its vault, treasury and config
    */
-  async claimTokens(
+  async closeLaunch(
     launchId: BN,
-    investor: Keypair
+    creator: Keypair
   ): Promise<TransactionResult> {
     try {
       const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
-      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor.publicKey);
-      
-      // Get launch config to find token mint
+      const [treasuryPDA] = this.getTreasuryPDA(launchId);
+
       const launchConfig = await this.getLaunchConfig(launchId);
       if (!launchConfig) {
         throw new Error("Launch not found");
@@ -398,41 +2187,184 @@ export class LaunchpadSDK {
       const tokenVault = await getAssociatedTokenAddress(
         launchConfig.tokenMint,
         launchConfigPDA,
-        true
+        true,
+        launchConfig.tokenProgram
       );
-
-      const investorTokenAccount = await getAssociatedTokenAddress(
+      const creatorTokenAccount = await getAssociatedTokenAddress(
         launchConfig.tokenMint,
-        investor.publicKey
+        creator.publicKey,
+        false,
+        launchConfig.tokenProgram
       );
 
-      // Create associated token account if it doesn't exist
-      const accountInfo = await this.connection.getAccountInfo(investorTokenAccount);
+      // Create the creator token account if it doesn't exist
+      const accountInfo = await this.connection.getAccountInfo(creatorTokenAccount);
       const instructions = [];
-      
+
       if (!accountInfo) {
         instructions.push(
           createAssociatedTokenAccountInstruction(
-            investor.publicKey,
-            investorTokenAccount,
-            investor.publicKey,
-            launchConfig.tokenMint
+            creator.publicKey,
+            creatorTokenAccount,
+            creator.publicKey,
+            launchConfig.tokenMint,
+            launchConfig.tokenProgram
           )
         );
       }
 
       const tx = await this.program.methods
-        .claimTokens(launchId)
+        .closeLaunch(launchId)
         .accounts({
           launchConfig: launchConfigPDA,
-          investorAccount: investorAccountPDA,
+          launchMetadata: this.getLaunchMetadataPDA(launchId)[0],
+          tokenMint: launchConfig.tokenMint,
           tokenVault: tokenVault,
-          investorTokenAccount: investorTokenAccount,
-          investor: investor.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          creatorTokenAccount: creatorTokenAccount,
+          treasuryAccount: treasuryPDA,
+          milestoneSchedule: launchConfig.usesMilestones
+            ? this.getMilestoneSchedulePDA(launchId)[0]
+            : null,
+          endedRegistry: this.getLaunchRegistryPDAForLaunch(RegistryBucket.Ended, launchId)[0],
+          platformConfig: this.getPlatformConfigPDA()[0],
+          platformStats: this.getPlatformStatsPDA()[0],
+          platformTreasury: platformConfig.treasury,
+          creator: creator.publicKey,
+          tokenProgram: launchConfig.tokenProgram,
+          systemProgram: SystemProgram.programId,
         })
         .preInstructions(instructions)
-        .signers([investor])
+        .signers([creator])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  // ============================================================================
+  // Migration Methods
+  // ============================================================================
+
+  /**
+   * Rebuild a platform config created before accounts were versioned in the current layout
+   */
+  async migratePlatformConfig(
+    payer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+
+      const tx = await this.program.methods
+        .migratePlatformConfig()
+        .accounts({
+          platformConfig: platformConfigPDA,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Create the platform statistics account of a platform initialized before it existed
+   */
+  async initializePlatformStats(
+    payer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [platformConfigPDA] = this.getPlatformConfigPDA();
+      const [platformStatsPDA] = this.getPlatformStatsPDA();
+
+      const tx = await this.program.methods
+        .initializePlatformStats()
+        .accounts({
+          platformConfig: platformConfigPDA,
+          platformStats: platformStatsPDA,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Rebuild a launch config created before accounts were versioned in the current layout,
+   * moving its inline metadata into its own account
+   */
+  async migrateLaunchConfig(
+    launchId: BN,
+    tokenMint: PublicKey,
+    payer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [launchConfigPDA] = this.getLaunchConfigPDA(launchId);
+
+      const tx = await this.program.methods
+        .migrateLaunchConfig(launchId)
+        .accounts({
+          launchConfig: launchConfigPDA,
+          launchMetadata: this.getLaunchMetadataPDA(launchId)[0],
+          tokenMint: tokenMint,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      return { signature: tx, success: true };
+    } catch (error) {
+      return {
+        signature: "",
+        success: false,
+        error: error instanceof Error ? error.message : "Unknown error",
+      };
+    }
+  }
+
+  /**
+   * Rebuild an investor account created before accounts were versioned in the current layout
+   */
+  async migrateInvestorAccount(
+    launchId: BN,
+    investor: PublicKey,
+    payer: Keypair
+  ): Promise<TransactionResult> {
+    try {
+      const [investorAccountPDA] = this.getInvestorAccountPDA(launchId, investor);
+
+      const tx = await this.program.methods
+        .migrateInvestorAccount(launchId)
+        .accounts({
+          investorAccount: investorAccountPDA,
+          investor: investor,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payer])
         .rpc();
 
       return { signature: tx, success: true };
@@ -461,6 +2393,18 @@ export class LaunchpadSDK {
     }
   }
 
+  /**
+   * Get the platform statistics account
+   */
+  async getPlatformStatsAccount(): Promise<PlatformStatsAccount | null> {
+    try {
+      const [platformStatsPDA] = this.getPlatformStatsPDA();
+      return await this.program.account.platformStats.fetch(platformStatsPDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
   /**
    * Get launch configuration
    */
@@ -473,6 +2417,54 @@ export class LaunchpadSDK {
     }
   }
 
+  /**
+   * Get a creator's profile
+   */
+  async getCreatorProfile(creator: PublicKey): Promise<CreatorProfile | null> {
+    try {
+      const [creatorProfilePDA] = this.getCreatorProfilePDA(creator);
+      return await this.program.account.creatorProfile.fetch(creatorProfilePDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
+  /**
+   * List the launch IDs in a status bucket, reading every registry page
+   * that can hold a created launch. Missing pages are empty.
+   */
+  async listLaunches(bucket: RegistryBucket): Promise<BN[]> {
+    const platformConfig = await this.getPlatformConfig();
+    if (!platformConfig) {
+      return [];
+    }
+
+    const lastPage = platformConfig.lastLaunchId.divn(LaunchpadSDK.REGISTRY_PAGE_SIZE).toNumber();
+    const pagePDAs = [];
+    for (let page = 0; page <= lastPage; page++) {
+      pagePDAs.push(this.getLaunchRegistryPDA(bucket, new BN(page))[0]);
+    }
+
+    const pages = (await this.program.account.launchRegistry.fetchMultiple(
+      pagePDAs
+    )) as (LaunchRegistry | null)[];
+    return pages
+      .flatMap((page) => (page ? page.launchIds : []))
+      .sort((a, b) => a.cmp(b));
+  }
+
+  /**
+   * Get launch metadata
+   */
+  async getLaunchMetadata(launchId: BN): Promise<LaunchMetadataAccount | null> {
+    try {
+      const [launchMetadataPDA] = this.getLaunchMetadataPDA(launchId);
+      return await this.program.account.launchMetadataAccount.fetch(launchMetadataPDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
   /**
    * Get investor account
    */
@@ -488,6 +2480,125 @@ export class LaunchpadSDK {
     }
   }
 
+  /**
+   * Get a wallet's stake account
+   */
+  async getStakeAccount(owner: PublicKey): Promise<StakeAccount | null> {
+    try {
+      const [stakeAccountPDA] = this.getStakeAccountPDA(owner);
+      return await this.program.account.stakeAccount.fetch(stakeAccountPDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
+  /**
+   * Get the token program that owns a mint
+   */
+  async getMintTokenProgram(mint: PublicKey): Promise<PublicKey> {
+    const mintInfo = await this.connection.getAccountInfo(mint);
+    if (!mintInfo) {
+      throw new Error("Mint not found");
+    }
+    return mintInfo.owner;
+  }
+
+  /**
+   * Get an investor's recorded contributions, withdrawals, claims and refunds for a launch
+   */
+  async getContributionHistory(
+    launchId: BN,
+    investor: PublicKey
+  ): Promise<LedgerEntry[]> {
+    const investorAccount = await this.getInvestorAccount(launchId, investor);
+    if (!investorAccount || investorAccount.ledgerEntries.isZero()) {
+      return [];
+    }
+
+    const lastPage = investorAccount.ledgerEntries
+      .subn(1)
+      .divn(LaunchpadSDK.LEDGER_PAGE_SIZE)
+      .toNumber();
+    const pagePDAs = [];
+    for (let page = 0; page <= lastPage; page++) {
+      pagePDAs.push(this.getContributionLedgerPDA(launchId, investor, new BN(page))[0]);
+    }
+
+    const pages = (await this.program.account.contributionLedger.fetchMultiple(
+      pagePDAs
+    )) as (ContributionLedger | null)[];
+    return pages.flatMap((page) => (page ? page.entries : []));
+  }
+
+  /**
+   * Get milestone schedule
+   */
+  async getMilestoneSchedule(launchId: BN): Promise<MilestoneSchedule | null> {
+    try {
+      const [milestoneSchedulePDA] = this.getMilestoneSchedulePDA(launchId);
+      return await this.program.account.milestoneSchedule.fetch(milestoneSchedulePDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
+  /**
+   * Get refund holding account
+   */
+  async getRefundHolding(launchId: BN): Promise<RefundHolding | null> {
+    try {
+      const [refundHoldingPDA] = this.getRefundHoldingPDA(launchId);
+      return await this.program.account.refundHolding.fetch(refundHoldingPDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
+  /**
+   * Get governance proposal
+   */
+  async getProposal(launchId: BN, proposalId: BN): Promise<Proposal | null> {
+    try {
+      const [proposalPDA] = this.getProposalPDA(launchId, proposalId);
+      return await this.program.account.proposal.fetch(proposalPDA);
+    } catch (error) {
+      return null;
+    }
+  }
+
+  /**
+   * Decode the events emitted through event CPI by a confirmed transaction
+   */
+  async getTransactionEvents(signature: string): Promise<LaunchpadEvent[]> {
+    const tx = await this.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    if (!tx || !tx.meta || !tx.meta.innerInstructions) return [];
+
+    const accountKeys = tx.transaction.message.getAccountKeys({
+      accountKeysFromLookups: tx.meta.loadedAddresses,
+    });
+
+    const events: LaunchpadEvent[] = [];
+    for (const inner of tx.meta.innerInstructions) {
+      for (const ix of inner.instructions) {
+        const programId = accountKeys.get(ix.programIdIndex);
+        if (!programId || !programId.equals(this.config.programId)) continue;
+
+        const data = Buffer.from(utils.bytes.bs58.decode(ix.data));
+        if (!data.subarray(0, 8).equals(LaunchpadSDK.EVENT_IX_TAG)) continue;
+
+        const event = this.program.coder.events.decode(data.subarray(8).toString("base64"));
+        if (event) {
+          events.push({ name: event.name, data: event.data });
+        }
+      }
+    }
+
+    return events;
+  }
+
   // ============================================================================
   // Analytics and Statistics Methods
   // ============================================================================
@@ -554,16 +2665,21 @@ export class LaunchpadSDK {
    */
   async getPlatformStats(): Promise<PlatformStats | null> {
     const platformConfig = await this.getPlatformConfig();
-    if (!platformConfig) return null;
+    const stats = await this.getPlatformStatsAccount();
+    if (!platformConfig || !stats) return null;
 
     // These would require additional queries to get accurate data
     const totalValueLocked = new BN(0); // Sum of all active launch treasuries
-    const activeLaunches = 0; // Count of launches with status Active
-    const successfulLaunches = 0; // Count of launches with status Successful
-    const averageRaise = new BN(0); // Average of successful launches
+    const activeLaunches = (await this.listLaunches(RegistryBucket.Active)).length;
+    const successfulLaunches = stats.launchesSucceeded.toNumber();
+    // Raised amounts are recorded when a launch is finalized as successful
+    const averageRaise = successfulLaunches > 0
+      ? stats.totalRaised.divn(successfulLaunches)
+      : new BN(0);
 
     return {
       config: platformConfig,
+      stats,
       totalValueLocked,
       activeLaunches,
       successfulLaunches,
@@ -13,6 +13,18 @@ export enum LaunchStatus {
   Paused = "paused",
 }
 
+/**
+ * Launch registry status buckets; the value is the bucket's PDA seed byte
+ */
+export enum RegistryBucket {
+  /** Awaiting admin review */
+  Pending = 0,
+  /** Approved and not yet finalized, including paused launches */
+  Active = 1,
+  /** Finalized, rejected or cancelled */
+  Ended = 2,
+}
+
 /**
  * Vesting configuration for token releases
  */
@@ -27,6 +39,72 @@ export interface VestingConfig {
   isLinear: boolean;
 }
 
+/**
+ * Investor governance parameters for a launch
+ */
+export interface GovernanceConfig {
+  /** Share of allocated tokens that must vote (in basis points, 10000 = 100%) */
+  quorumPercentage: number;
+  /** Share of cast votes required to pass (in basis points, 10000 = 100%) */
+  approvalThreshold: number;
+  /** Length of the voting period in seconds */
+  votingPeriod: BN;
+}
+
+/**
+ * Configuration for withdrawing contributions during the sale
+ */
+export interface WithdrawalConfig {
+  /** Whether investors can withdraw contributions while the sale is active */
+  allowWithdrawal: boolean;
+  /** Penalty charged on withdrawn amounts (in basis points, 10000 = 100%) */
+  penaltyPercentage: number;
+  /** Whether the penalty goes to the creator instead of the platform treasury */
+  penaltyToCreator: boolean;
+  /** Withdrawals close this many seconds before the presale ends */
+  cutoffPeriod: BN;
+}
+
+/**
+ * Token-2022 extensions enabled on a launch mint
+ */
+export interface TokenExtensionsConfig {
+  /** Store name, symbol and URI on the mint behind a metadata pointer */
+  embeddedMetadata: boolean;
+  /** URI of the off-chain metadata JSON for embedded metadata */
+  metadataUri: string;
+  /** Fee charged on every transfer (in basis points, 0 disables) */
+  transferFeeBasisPoints: number;
+  /** Maximum fee charged on a single transfer in base units */
+  maximumFee: BN;
+  /** Whether tokens are soulbound to the investor's wallet */
+  nonTransferable: boolean;
+}
+
+/**
+ * What happens to the mint and freeze authority at finalization
+ */
+export type MintAuthorityPolicy =
+  | { revoke: {} }
+  | { transfer: { newAuthority: PublicKey } }
+  | { retain: {} };
+
+/**
+ * What happens to presale tokens left unsold below the hard cap
+ */
+export type UnsoldTokenPolicy =
+  | { burn: {} }
+  | { returnToCreator: {} }
+  | { addToLiquidity: { liquidityAccount: PublicKey } };
+
+/**
+ * When investors may move their positions to another wallet
+ */
+export type PositionTransferPolicy =
+  | { disabled: {} }
+  | { anytime: {} }
+  | { afterFinalization: {} };
+
 /**
  * Launch metadata containing project information
  */
@@ -47,6 +125,24 @@ export interface LaunchMetadata {
   documentation: string;
 }
 
+/**
+ * Launch metadata account, stored separately so it can be resized
+ */
+export interface LaunchMetadataAccount {
+  /** Launch this metadata belongs to */
+  launchId: BN;
+  /** Project metadata */
+  metadata: LaunchMetadata;
+  /** Whether the admin allows updates after approval */
+  updatesAllowed: boolean;
+  /** Last update time (Unix timestamp) */
+  updatedAt: BN;
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
+}
+
 /**
  * Launch configuration parameters
  */
@@ -87,10 +183,50 @@ export interface LaunchConfig {
   status: LaunchStatus;
   /** Vesting configuration */
   vestingConfig: VestingConfig;
-  /** Project metadata */
-  metadata: LaunchMetadata;
+  /** Creator bond escrowed in the launch account in lamports */
+  creatorBond: BN;
+  /** Contributions attributed to referrers who have not claimed, in lamports */
+  referredVolume: BN;
+  /** Platform fee share reserved for referrers and not yet claimed, in lamports */
+  referralPool: BN;
+  /** Whether creator proceeds are escrowed behind milestones */
+  usesMilestones: boolean;
+  /** Investor governance parameters */
+  governanceConfig: GovernanceConfig;
+  /** Address receiving creator proceeds */
+  payoutAddress: PublicKey;
+  /** Number of governance proposals created */
+  proposalCount: BN;
+  /** Investor accounts not yet closed */
+  openInvestorAccounts: number;
+  /** Contribution withdrawal settings */
+  withdrawalConfig: WithdrawalConfig;
+  /** Whether unclaimed refunds were swept to the refund holding account */
+  refundsSwept: boolean;
+  /** Token program owning the mint (SPL Token or Token-2022) */
+  tokenProgram: PublicKey;
+  /** Token-2022 extensions enabled on the mint */
+  tokenExtensions: TokenExtensionsConfig;
+  /** Whether tokens are sold from a deposited supply of an existing mint */
+  usesExistingMint: boolean;
+  /** Tokens deposited into the vault for an existing-mint launch */
+  depositedTokens: BN;
+  /** What happens to the mint and freeze authority at finalization */
+  mintAuthorityPolicy: MintAuthorityPolicy;
+  /** What happens to presale tokens left unsold below the hard cap */
+  unsoldTokenPolicy: UnsoldTokenPolicy;
   /** Bump seed for PDA */
   bump: number;
+  /** Account layout version (0 = created before versioning) */
+  version: number;
+  /** When investors may move their positions to another wallet */
+  positionTransferPolicy: PositionTransferPolicy;
+  /** Whether each position is represented by an NFT minted on the first contribution */
+  positionNfts: boolean;
+  /** Guaranteed allocations reserved by stakers and not yet contributed, in lamports; held back only until reservations lapse */
+  reservedAllocation: BN;
+  /** Whether finalize_launch has settled the launch (a launch at its hard cap is successful before that) */
+  finalized: boolean;
 }
 
 /**
@@ -111,6 +247,212 @@ export interface InvestorAccount {
   lastClaimTime: BN;
   /** Whether the investor has been refunded */
   isRefunded: boolean;
+  /** Whether insurance compensation has been claimed */
+  insuranceClaimed: boolean;
+  /** Whether the milestone escrow refund has been claimed */
+  milestoneRefunded: boolean;
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version (0 = created before versioning) */
+  version: number;
+  /** Entries recorded in the investor's contribution ledger (0 = no ledger kept) */
+  ledgerEntries: BN;
+  /** Unix timestamp the position last received a transfer (0 = never) */
+  positionReceivedAt: BN;
+  /** Mint of the position NFT (PublicKey.default = position not tokenized) */
+  positionMint: PublicKey;
+  /** Referrer wallet the contributions are attributed to (PublicKey.default = none) */
+  referrer: PublicKey;
+  /** Part of the contribution attributed to the referrer, in lamports */
+  referredAmount: BN;
+  /** Token allocation received by transfer; it does not vote in votes opened before positionReceivedAt */
+  receivedAllocation: BN;
+}
+
+/**
+ * Kind of position change recorded in a contribution ledger
+ */
+export enum LedgerEntryKind {
+  Contribution = "contribution",
+  Withdrawal = "withdrawal",
+  Claim = "claim",
+  Refund = "refund",
+  MilestoneRefund = "milestoneRefund",
+  TransferOut = "transferOut",
+  TransferIn = "transferIn",
+}
+
+/**
+ * One recorded position change
+ */
+export interface LedgerEntry {
+  /** What changed */
+  kind: LedgerEntryKind;
+  /** Lamports moved, or token base units for claims and transfers */
+  amount: BN;
+  /** Presale price in lamports per token at the time */
+  price: BN;
+  /** Unix timestamp of the instruction */
+  timestamp: BN;
+  /** Slot of the instruction */
+  slot: BN;
+}
+
+/**
+ * One page of an investor's contribution ledger for a launch
+ */
+export interface ContributionLedger {
+  /** Launch the history belongs to */
+  launchId: BN;
+  /** Investor the history belongs to */
+  investor: PublicKey;
+  /** Page index; entry `n` is stored on page `n / 16` */
+  page: BN;
+  /** Entries in the order they were recorded */
+  entries: LedgerEntry[];
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
+}
+
+/**
+ * Referrer account data
+ */
+export interface Referrer {
+  /** The referrer's public key */
+  referrer: PublicKey;
+  /** Launch ID this referrer is registered for */
+  launchId: BN;
+  /** Total contributions referred in lamports */
+  referredVolume: BN;
+  /** Number of referred contributions */
+  referralCount: number;
+  /** Rewards paid out in lamports */
+  claimedAmount: BN;
+  /** Whether rewards have been claimed */
+  rewardsClaimed: boolean;
+  /** Bump seed for PDA */
+  bump: number;
+}
+
+/**
+ * Milestone status enumeration
+ */
+export enum MilestoneStatus {
+  Locked = "locked",
+  Voting = "voting",
+  Released = "released",
+  Rejected = "rejected",
+}
+
+/**
+ * Milestone parameters supplied by the creator
+ */
+export interface MilestoneParams {
+  /** Earliest time a release vote can be opened (Unix timestamp) */
+  unlockTime: BN;
+  /** Share of escrowed funds released in basis points */
+  releasePercentage: number;
+}
+
+/**
+ * A single milestone tranche
+ */
+export interface Milestone {
+  /** Earliest time a release vote can be opened (Unix timestamp) */
+  unlockTime: BN;
+  /** Share of escrowed funds released in basis points */
+  releasePercentage: number;
+  /** Current status of the tranche */
+  status: MilestoneStatus;
+  /** End of the current vote (Unix timestamp) */
+  votingEndsAt: BN;
+  /** Contribution-weighted votes in favour */
+  votesFor: BN;
+  /** Contribution-weighted votes against */
+  votesAgainst: BN;
+  /** Incremented each time a vote is opened */
+  voteRound: number;
+}
+
+/**
+ * Milestone schedule and escrow state
+ */
+export interface MilestoneSchedule {
+  /** Launch ID this schedule belongs to */
+  launchId: BN;
+  /** Ordered tranches */
+  milestones: Milestone[];
+  /** Creator proceeds held at finalization in lamports */
+  escrowedAmount: BN;
+  /** Amount released to the creator in lamports */
+  releasedAmount: BN;
+  /** Unreleased funds refundable to investors in lamports */
+  refundPool: BN;
+  /** Whether investors can claim escrow refunds */
+  refundsEnabled: boolean;
+  /** Bump seed for PDA */
+  bump: number;
+}
+
+/**
+ * Action a governance proposal executes when it passes
+ */
+export type ProposalKind =
+  | { extendVesting: { additionalDuration: BN } }
+  | { triggerRefund: {} }
+  | { replacePayoutAddress: { newPayoutAddress: PublicKey } };
+
+/**
+ * Proposal status enumeration
+ */
+export enum ProposalStatus {
+  Voting = "voting",
+  Executed = "executed",
+  Defeated = "defeated",
+}
+
+/**
+ * Investor governance proposal
+ */
+export interface Proposal {
+  /** Launch ID the proposal belongs to */
+  launchId: BN;
+  /** Sequential proposal ID within the launch */
+  proposalId: BN;
+  /** Investor who created the proposal */
+  proposer: PublicKey;
+  /** Action executed if the proposal passes */
+  kind: ProposalKind;
+  /** Current status of the proposal */
+  status: ProposalStatus;
+  /** Allocation-weighted votes in favour */
+  votesFor: BN;
+  /** Allocation-weighted votes against */
+  votesAgainst: BN;
+  /** Creation time (Unix timestamp) */
+  createdAt: BN;
+  /** End of the voting period (Unix timestamp) */
+  votingEndsAt: BN;
+  /** Bump seed for PDA */
+  bump: number;
+}
+
+/**
+ * Unclaimed refunds swept from a failed launch's treasury
+ */
+export interface RefundHolding {
+  /** Launch ID the refunds belong to */
+  launchId: BN;
+  /** Lamports moved from the treasury */
+  sweptAmount: BN;
+  /** Lamports paid out to late claimants */
+  claimedAmount: BN;
+  /** Sweep time (Unix timestamp) */
+  sweptAt: BN;
+  /** Presale price of the launch, recorded in ledgers after the launch is closed */
+  presalePrice: BN;
   /** Bump seed for PDA */
   bump: number;
 }
@@ -131,23 +473,148 @@ export interface PlatformConfig {
   maxLaunchDuration: BN;
   /** Minimum soft cap amount in lamports */
   minSoftCap: BN;
+  /** Fee charged on launch creation in lamports */
+  listingFee: BN;
+  /** Refundable bond escrowed per launch in lamports */
+  creatorBondAmount: BN;
+  /** Share of the platform fee paid to referrers in basis points */
+  referralFeeShare: number;
+  /** Share of the platform fee deposited into the insurance fund in basis points */
+  insuranceFeeShare: number;
+  /** Hard cap at or above which milestones are required in lamports (0 disables) */
+  milestoneThreshold: BN;
+  /** Seconds after a failed launch ends before refunds can be swept (0 disables) */
+  refundWindow: BN;
   /** Whether the platform is paused */
   isPaused: boolean;
-  /** Total number of launches created */
+  /** Number of launches finalized as successful (superseded by PlatformStatsAccount, no longer updated) */
   totalLaunches: BN;
-  /** Total amount raised across all launches */
+  /** Total amount raised across all launches (superseded by PlatformStatsAccount, no longer updated) */
   totalRaised: BN;
-  /** Total fees collected by platform */
+  /** Total fees collected by platform (superseded by PlatformStatsAccount, no longer updated) */
   totalFeesCollected: BN;
   /** Bump seed for PDA */
   bump: number;
+  /** Account layout version (0 = created before versioning) */
+  version: number;
+  /** Identifier assigned to the most recent launch (launch IDs start at 1) */
+  lastLaunchId: BN;
+  /** Mint of the staked platform token (default key = staking not configured) */
+  stakeMint: PublicKey;
+  /** Allocation tiers in increasing order of minimum stake */
+  stakingTiers: StakingTier[];
+}
+
+/**
+ * Allocation tier unlocked by staking the platform token
+ */
+export interface StakingTier {
+  /** Minimum stake in platform token base units */
+  minStake: BN;
+  /** Minimum lock period in seconds (0 = unlocked stake counts) */
+  minLockDuration: BN;
+  /** Multiplier applied to a launch's max contribution in basis points (10000 = 1x) */
+  allocationMultiplier: number;
+  /** Contribution in lamports the tier may always make (0 = none) */
+  guaranteedAllocation: BN;
+}
+
+/**
+ * Platform tokens staked by a wallet
+ */
+export interface StakeAccount {
+  /** Staking wallet */
+  owner: PublicKey;
+  /** Tokens staked in platform token base units */
+  amount: BN;
+  /** Longest lock period in seconds committed to since the last unstake */
+  lockDuration: BN;
+  /** Unix timestamp until which the stake cannot be withdrawn */
+  lockedUntil: BN;
+  /** Launch the guaranteed allocation is reserved in (0 = none) */
+  reservedLaunchId: BN;
+  /** Guaranteed allocation reserved in reservedLaunchId and not yet contributed, in lamports */
+  reservedAllocation: BN;
+  /** Unix timestamp at which the reservation lapses */
+  reservationExpiresAt: BN;
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
+}
+
+/**
+ * Platform-wide statistics account
+ */
+export interface PlatformStatsAccount {
+  /** Number of launches created */
+  launchesCreated: BN;
+  /** Number of launches finalized as successful */
+  launchesSucceeded: BN;
+  /** Number of launches finalized below their soft cap */
+  launchesFailed: BN;
+  /** Number of launches cancelled after approval */
+  launchesCancelled: BN;
+  /** Number of launches rejected in review */
+  launchesRejected: BN;
+  /** Investor positions opened by a contribution or transfer, summed over launches (not unique wallets) */
+  launchContributors: BN;
+  /** Total raised by successful launches in lamports */
+  totalRaised: BN;
+  /** Total refunded to investors in lamports, including milestone refunds */
+  totalRefunded: BN;
+  /** Fees paid into the platform treasury in lamports */
+  feesAccrued: BN;
+  /** Fees withdrawn from the platform treasury in lamports */
+  feesWithdrawn: BN;
+  /** Presale tokens claimed by or distributed to investors, in base units summed across mints */
+  tokensClaimed: BN;
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
+}
+
+/**
+ * A creator's launches and lifetime results
+ */
+export interface CreatorProfile {
+  /** Creator the profile belongs to */
+  creator: PublicKey;
+  /** Number of launches created */
+  launchesCreated: BN;
+  /** Number of launches finalized as successful */
+  launchesSucceeded: BN;
+  /** Total raised by successful launches in lamports */
+  totalRaised: BN;
+  /** IDs of the creator's launches in creation order */
+  launchIds: BN[];
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
+}
+
+/**
+ * One page of launch IDs in a registry status bucket
+ */
+export interface LaunchRegistry {
+  /** Status bucket of the page */
+  bucket: { pending: {} } | { active: {} } | { ended: {} };
+  /** Page index; launch `n` is listed on page `n / 64` */
+  page: BN;
+  /** Launch IDs currently in the bucket, unordered */
+  launchIds: BN[];
+  /** Bump seed for PDA */
+  bump: number;
+  /** Account layout version */
+  version: number;
 }
 
 /**
- * Parameters for creating a new launch
+ * Parameters for creating a new launch; the program assigns the launch ID
  */
 export interface CreateLaunchParams {
-  launchId: BN;
   name: string;
   symbol: string;
   decimals: number;
@@ -160,6 +627,33 @@ export interface CreateLaunchParams {
   startTime: BN;
   endTime: BN;
   vestingConfig: VestingConfig;
+  governanceConfig: GovernanceConfig;
+  withdrawalConfig: WithdrawalConfig;
+  metadata: LaunchMetadata;
+  /** Create the mint under Token-2022 (required for extensions) */
+  useToken2022?: boolean;
+  tokenExtensions: TokenExtensionsConfig;
+  mintAuthorityPolicy: MintAuthorityPolicy;
+  unsoldTokenPolicy: UnsoldTokenPolicy;
+  /** When investors may move their positions (defaults to disabled) */
+  positionTransferPolicy?: PositionTransferPolicy;
+  /** Mint a position NFT on each investor's first contribution (defaults to false) */
+  positionNfts?: boolean;
+}
+
+/**
+ * Parameters for editing a launch that is awaiting review
+ */
+export interface UpdateLaunchParams {
+  launchId: BN;
+  presalePrice: BN;
+  minContribution: BN;
+  maxContribution: BN;
+  softCap: BN;
+  hardCap: BN;
+  startTime: BN;
+  endTime: BN;
+  vestingConfig: VestingConfig;
   metadata: LaunchMetadata;
 }
 
@@ -174,6 +668,34 @@ export interface InitializePlatformParams {
   treasury: PublicKey;
 }
 
+/**
+ * Platform settings to change; omitted fields keep their current value
+ */
+export interface PlatformConfigUpdate {
+  /** Platform fee percentage in basis points */
+  platformFeePercentage?: number;
+  /** Minimum launch duration in seconds */
+  minLaunchDuration?: BN;
+  /** Maximum launch duration in seconds */
+  maxLaunchDuration?: BN;
+  /** Minimum soft cap in lamports */
+  minSoftCap?: BN;
+  /** Listing fee in lamports charged on launch creation */
+  listingFee?: BN;
+  /** Refundable bond in lamports escrowed per launch (0 = disabled) */
+  creatorBondAmount?: BN;
+  /** Share of the platform fee paid to referrers in basis points */
+  referralFeeShare?: number;
+  /** Share of the platform fee deposited into the insurance fund in basis points */
+  insuranceFeeShare?: number;
+  /** Hard cap in lamports at or above which a milestone schedule is required (0 = never) */
+  milestoneThreshold?: BN;
+  /** Seconds after a failed launch ends before unclaimed refunds can be swept (0 = never) */
+  refundWindow?: BN;
+  /** Launch ID to skip to, so the next launch is assigned the ID after it */
+  lastLaunchId?: BN;
+}
+
 /**
  * SDK configuration options
  */
@@ -196,6 +718,8 @@ export interface TransactionResult {
   success: boolean;
   /** Error message if transaction failed */
   error?: string;
+  /** Launch ID assigned by the program when creating a launch */
+  launchId?: BN;
 }
 
 /**
@@ -236,6 +760,8 @@ export interface InvestorPosition {
 export interface PlatformStats {
   /** Platform configuration */
   config: PlatformConfig;
+  /** Platform statistics account */
+  stats: PlatformStatsAccount;
   /** Total value locked in lamports */
   totalValueLocked: BN;
   /** Number of active launches */
@@ -244,4 +770,14 @@ export interface PlatformStats {
   successfulLaunches: number;
   /** Average raise amount */
   averageRaise: BN;
-}
\ No newline at end of file
+}
+
+/**
+ * Event decoded from an event CPI of a launchpad transaction
+ */
+export interface LaunchpadEvent {
+  /** Event name, e.g. "ContributionMade" */
+  name: string;
+  /** Decoded event fields */
+  data: any;
+}
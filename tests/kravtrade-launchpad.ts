@@ -6,13 +6,25 @@ import {
   Keypair, 
   SystemProgram, 
   SYSVAR_RENT_PUBKEY,
-  LAMPORTS_PER_SOL 
+  LAMPORTS_PER_SOL,
+  Transaction,
+  sendAndConfirmTransaction
 } from "@solana/web3.js";
 import { 
   TOKEN_PROGRAM_ID, 
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeNonTransferableMintInstruction,
   getAssociatedTokenAddress,
-  createAssociatedTokenAccountInstruction
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccount,
+  createMint,
+  mintTo
 } from "@solana/spl-token";
 import { expect } from "chai";
 
@@ -33,12 +45,20 @@ describe("kravtrade-launchpad", () => {
   // PDAs
   let platformConfigPda: PublicKey;
   let launchConfigPda: PublicKey;
+  let launchMetadataPda: PublicKey;
+  let platformStatsPda: PublicKey;
+  let investor2LedgerPda: PublicKey;
+  let creatorProfilePda: PublicKey;
+  let pendingRegistryPda: PublicKey;
+  let activeRegistryPda: PublicKey;
+  let endedRegistryPda: PublicKey;
   let treasuryPda: PublicKey;
+  let insuranceFundPda: PublicKey;
   let investor1AccountPda: PublicKey;
   let investor2AccountPda: PublicKey;
 
   // Test data
-  const launchId = new anchor.BN(1);
+  const launchId = new anchor.BN(1); // Assigned by the program to the first launch
   const tokenName = "Test Token";
   const tokenSymbol = "TEST";
   const tokenDecimals = 9;
@@ -82,6 +102,46 @@ describe("kravtrade-launchpad", () => {
       program.programId
     );
 
+    [insuranceFundPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance")],
+      program.programId
+    );
+
+    [launchMetadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("launch_metadata"), launchId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    [investor2LedgerPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_ledger"),
+        launchId.toArrayLike(Buffer, "le", 8),
+        investor2.publicKey.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    [platformStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("platform"), Buffer.from("stats")],
+      program.programId
+    );
+
+    [creatorProfilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_profile"), creator.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Launch IDs below 64 are listed on page 0 of each status bucket
+    const registryPage = (bucket: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("launch_registry"), Buffer.from([bucket]), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    pendingRegistryPda = registryPage(0);
+    activeRegistryPda = registryPage(1);
+    endedRegistryPda = registryPage(2);
+
     [treasuryPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("treasury"), launchId.toArrayLike(Buffer, "le", 8)],
       program.programId
@@ -106,6 +166,144 @@ describe("kravtrade-launchpad", () => {
     );
   });
 
+  const launchPda = (seed: string, id: anchor.BN, wallet?: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), id.toArrayLike(Buffer, "le", 8), ...(wallet ? [wallet.toBuffer()] : [])],
+      program.programId
+    )[0];
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // Create and approve a further launch with the shared sale parameters, open for contributions
+  const createApprovedLaunch = async (id: anchor.BN, saleMaxContribution = maxContribution) => {
+    const mint = Keypair.generate();
+    const launchConfig = launchPda("launch", id);
+    const currentTime = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createLaunch(
+        tokenName,
+        tokenSymbol,
+        tokenDecimals,
+        totalSupply,
+        presalePrice,
+        minContribution,
+        saleMaxContribution,
+        softCap,
+        hardCap,
+        new anchor.BN(currentTime + 2),
+        new anchor.BN(currentTime + 7 * 24 * 3600),
+        {
+          cliffDuration: new anchor.BN(0),
+          vestingDuration: new anchor.BN(0),
+          initialUnlockPercentage: 10000,
+          isLinear: false,
+        },
+        {
+          quorumPercentage: 2000,
+          approvalThreshold: 5000,
+          votingPeriod: new anchor.BN(3 * 24 * 3600),
+        },
+        {
+          allowWithdrawal: false,
+          penaltyPercentage: 0,
+          penaltyToCreator: false,
+          cutoffPeriod: new anchor.BN(0),
+        },
+        {
+          description: "Crank test token",
+          website: "",
+          twitter: "",
+          telegram: "",
+          discord: "",
+          logoUri: "",
+          documentation: "",
+        },
+        {
+          embeddedMetadata: false,
+          metadataUri: "",
+          transferFeeBasisPoints: 0,
+          maximumFee: new anchor.BN(0),
+          nonTransferable: false,
+        },
+        { revoke: {} },
+        { returnToCreator: {} },
+        { disabled: {} },
+        false
+      )
+      .accounts({
+        launchConfig: launchConfig,
+        launchMetadata: launchPda("launch_metadata", id),
+        creatorProfile: creatorProfilePda,
+        pendingRegistry: pendingRegistryPda,
+        platformConfig: platformConfigPda,
+        platformStats: platformStatsPda,
+        platformTreasury: treasury.publicKey,
+        tokenMint: mint.publicKey,
+        tokenVault: await getAssociatedTokenAddress(mint.publicKey, launchConfig, true),
+        creator: creator.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([creator, mint])
+      .rpc();
+
+    await program.methods
+      .approveLaunch(id)
+      .accounts({
+        launchConfig: launchConfig,
+        platformConfig: platformConfigPda,
+        pendingRegistry: pendingRegistryPda,
+        activeRegistry: activeRegistryPda,
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // Wait for the sale to open
+    await sleep(3000);
+
+    return { launchConfig, tokenMint: mint.publicKey, treasuryAccount: launchPda("treasury", id) };
+  };
+
+  const ledgerPage = (id: anchor.BN, wallet: PublicKey, page: number) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("contribution_ledger"),
+        id.toArrayLike(Buffer, "le", 8),
+        wallet.toBuffer(),
+        new anchor.BN(page).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  const contributeTo = async (
+    id: anchor.BN,
+    investor: Keypair,
+    amount: anchor.BN,
+    contributionLedger: PublicKey | null = null,
+    stakeAccount: PublicKey | null = null
+  ) => {
+    await program.methods
+      .contribute(amount)
+      .accounts({
+        launchConfig: launchPda("launch", id),
+        investorAccount: launchPda("investor", id, investor.publicKey),
+        platformConfig: platformConfigPda,
+        platformStats: platformStatsPda,
+        treasuryAccount: launchPda("treasury", id),
+        referrerAccount: null,
+        stakeAccount: stakeAccount,
+        contributionLedger: contributionLedger,
+        investor: investor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([investor])
+      .rpc();
+  };
+
   describe("Platform Initialization", () => {
     it("Should initialize platform configuration", async () => {
       const platformFeePercentage = 250; // 2.5%
@@ -122,6 +320,7 @@ describe("kravtrade-launchpad", () => {
         )
         .accounts({
           platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
           admin: admin.publicKey,
           treasury: treasury.publicKey,
           systemProgram: SystemProgram.programId,
@@ -136,6 +335,49 @@ describe("kravtrade-launchpad", () => {
       expect(platformConfig.platformFeePercentage).to.equal(platformFeePercentage);
       expect(platformConfig.isPaused).to.be.false;
       expect(platformConfig.totalLaunches.toNumber()).to.equal(0);
+      expect(platformConfig.lastLaunchId.toNumber()).to.equal(0);
+      expect(platformConfig.version).to.equal(1);
+
+      // Statistics live in their own account, created alongside the config
+      const platformStats = await program.account.platformStats.fetch(platformStatsPda);
+      expect(platformStats.launchesCreated.toNumber()).to.equal(0);
+      expect(platformStats.feesWithdrawn.toNumber()).to.equal(0);
+      expect(platformStats.version).to.equal(1);
+    });
+
+    it("Should not migrate a platform config that is already current", async () => {
+      try {
+        await program.methods
+          .migratePlatformConfig()
+          .accounts({
+            platformConfig: platformConfigPda,
+            payer: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed for a current platform config");
+      } catch (error) {
+        expect(error.message).to.include("AccountAlreadyMigrated");
+      }
+    });
+
+    it("Should initialize the insurance fund", async () => {
+      await program.methods
+        .initializeInsuranceFund()
+        .accounts({
+          insuranceFund: insuranceFundPda,
+          platformConfig: platformConfigPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const insuranceFund = await program.account.insuranceFund.fetch(insuranceFundPda);
+      expect(insuranceFund.totalDeposited.toNumber()).to.equal(0);
+      expect(insuranceFund.totalCommitted.toNumber()).to.equal(0);
     });
 
     it("Should fail with invalid platform fee", async () => {
@@ -151,6 +393,7 @@ describe("kravtrade-launchpad", () => {
           )
           .accounts({
             platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
             admin: admin.publicKey,
             treasury: treasury.publicKey,
             systemProgram: SystemProgram.programId,
@@ -173,6 +416,83 @@ describe("kravtrade-launchpad", () => {
       tokenMint = Keypair.generate();
     });
 
+    // Try to create the next launch selling an existing mint
+    const createExistingMintLaunch = async (mint: PublicKey, tokenProgram: PublicKey) => {
+      const nextLaunchId = new anchor.BN(2);
+      const launchConfig = launchPda("launch", nextLaunchId);
+      const currentTime = Math.floor(Date.now() / 1000);
+
+      await program.methods
+        .createLaunch(
+          tokenName,
+          tokenSymbol,
+          tokenDecimals,
+          totalSupply,
+          presalePrice,
+          minContribution,
+          maxContribution,
+          softCap,
+          hardCap,
+          new anchor.BN(currentTime + 3600),
+          new anchor.BN(currentTime + 7 * 24 * 3600),
+          {
+            cliffDuration: new anchor.BN(0),
+            vestingDuration: new anchor.BN(0),
+            initialUnlockPercentage: 10000,
+            isLinear: false,
+          },
+          {
+            quorumPercentage: 2000,
+            approvalThreshold: 5000,
+            votingPeriod: new anchor.BN(3 * 24 * 3600),
+          },
+          {
+            allowWithdrawal: false,
+            penaltyPercentage: 0,
+            penaltyToCreator: false,
+            cutoffPeriod: new anchor.BN(0),
+          },
+          {
+            description: "Existing mint",
+            website: "",
+            twitter: "",
+            telegram: "",
+            discord: "",
+            logoUri: "",
+            documentation: "",
+          },
+          {
+            embeddedMetadata: false,
+            metadataUri: "",
+            transferFeeBasisPoints: 0,
+            maximumFee: new anchor.BN(0),
+            nonTransferable: false,
+          },
+          { retain: {} },
+          { returnToCreator: {} },
+          { disabled: {} },
+          false
+        )
+        .accounts({
+          launchConfig: launchConfig,
+          launchMetadata: launchPda("launch_metadata", nextLaunchId),
+          creatorProfile: creatorProfilePda,
+          pendingRegistry: pendingRegistryPda,
+          platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
+          platformTreasury: treasury.publicKey,
+          tokenMint: mint,
+          tokenVault: getAssociatedTokenAddressSync(mint, launchConfig, true, tokenProgram),
+          creator: creator.publicKey,
+          tokenProgram: tokenProgram,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([creator])
+        .rpc();
+    };
+
     it("Should create a new launch", async () => {
       const currentTime = Math.floor(Date.now() / 1000);
       const startTime = new anchor.BN(currentTime + 3600); // 1 hour from now
@@ -191,6 +511,27 @@ describe("kravtrade-launchpad", () => {
         isLinear: true,
       };
 
+      const governanceConfig = {
+        quorumPercentage: 2000, // 20%
+        approvalThreshold: 5000, // simple majority
+        votingPeriod: new anchor.BN(3 * 24 * 3600), // 3 days
+      };
+
+      const withdrawalConfig = {
+        allowWithdrawal: true,
+        penaltyPercentage: 500, // 5%
+        penaltyToCreator: false,
+        cutoffPeriod: new anchor.BN(24 * 3600), // 1 day before end
+      };
+
+      const tokenExtensions = {
+        embeddedMetadata: false,
+        metadataUri: "",
+        transferFeeBasisPoints: 0,
+        maximumFee: new anchor.BN(0),
+        nonTransferable: false,
+      };
+
       const metadata = {
         description: "Test token for launchpad",
         website: "https://test.com",
@@ -203,7 +544,6 @@ describe("kravtrade-launchpad", () => {
 
       await program.methods
         .createLaunch(
-          launchId,
           tokenName,
           tokenSymbol,
           tokenDecimals,
@@ -216,11 +556,23 @@ describe("kravtrade-launchpad", () => {
           startTime,
           endTime,
           vestingConfig,
-          metadata
+          governanceConfig,
+          withdrawalConfig,
+          metadata,
+          tokenExtensions,
+          { revoke: {} },
+          { returnToCreator: {} },
+          { disabled: {} },
+          false
         )
         .accounts({
           launchConfig: launchConfigPda,
+          launchMetadata: launchMetadataPda,
+          creatorProfile: creatorProfilePda,
+          pendingRegistry: pendingRegistryPda,
           platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
+          platformTreasury: treasury.publicKey,
           tokenMint: tokenMint.publicKey,
           tokenVault: tokenVault,
           creator: creator.publicKey,
@@ -240,6 +592,98 @@ describe("kravtrade-launchpad", () => {
       expect(launchConfig.symbol).to.equal(tokenSymbol);
       expect(launchConfig.status).to.deep.equal({ pending: {} });
       expect(launchConfig.totalRaised.toNumber()).to.equal(0);
+      expect(launchConfig.tokenProgram.toString()).to.equal(TOKEN_PROGRAM_ID.toString());
+      expect(launchConfig.positionNfts).to.equal(false);
+
+      // The first launch is assigned ID 1 by the platform counter
+      const platformConfig = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(platformConfig.lastLaunchId.toNumber()).to.equal(launchId.toNumber());
+
+      const platformStats = await program.account.platformStats.fetch(platformStatsPda);
+      expect(platformStats.launchesCreated.toNumber()).to.equal(1);
+
+      // Discoverable through the creator profile and the pending registry
+      const creatorProfile = await program.account.creatorProfile.fetch(creatorProfilePda);
+      expect(creatorProfile.launchesCreated.toNumber()).to.equal(1);
+      expect(creatorProfile.launchIds.map((id) => id.toNumber())).to.deep.equal([launchId.toNumber()]);
+
+      const pendingRegistry = await program.account.launchRegistry.fetch(pendingRegistryPda);
+      expect(pendingRegistry.launchIds.map((id) => id.toNumber())).to.include(launchId.toNumber());
+    });
+
+    it("Should let the creator edit a pending launch", async () => {
+      const before = await program.account.launchConfig.fetch(launchConfigPda);
+
+      const vestingConfig = {
+        cliffDuration: new anchor.BN(7 * 24 * 3600), // 7 day cliff requested by review
+        vestingDuration: new anchor.BN(30 * 24 * 3600),
+        initialUnlockPercentage: 1000,
+        isLinear: true,
+      };
+
+      const metadata = {
+        description: "Test token for launchpad, revised after review",
+        website: "https://test.com",
+        twitter: "@test",
+        telegram: "https://t.me/test",
+        discord: "https://discord.gg/test",
+        logoUri: "https://test.com/logo.png",
+        documentation: "https://docs.test.com",
+      };
+
+      await program.methods
+        .updateLaunch(
+          launchId,
+          presalePrice,
+          minContribution,
+          maxContribution,
+          softCap,
+          hardCap,
+          before.startTime,
+          before.endTime,
+          vestingConfig,
+          metadata
+        )
+        .accounts({
+          launchConfig: launchConfigPda,
+          launchMetadata: launchMetadataPda,
+          milestoneSchedule: null,
+          platformConfig: platformConfigPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      expect(launchConfig.vestingConfig.cliffDuration.toNumber()).to.equal(7 * 24 * 3600);
+      expect(launchConfig.status).to.deep.equal({ pending: {} });
+
+      const launchMetadata = await program.account.launchMetadataAccount.fetch(launchMetadataPda);
+      expect(launchMetadata.metadata.description).to.equal(metadata.description);
+    });
+
+    it("Should not migrate a launch config that is already current", async () => {
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      expect(launchConfig.version).to.equal(1);
+
+      try {
+        await program.methods
+          .migrateLaunchConfig(launchId)
+          .accounts({
+            launchConfig: launchConfigPda,
+            launchMetadata: launchMetadataPda,
+            tokenMint: tokenMint.publicKey,
+            payer: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed for a current launch config");
+      } catch (error) {
+        expect(error.message).to.include("AccountAlreadyMigrated");
+      }
     });
 
     it("Should fail with invalid time parameters", async () => {
@@ -260,6 +704,27 @@ describe("kravtrade-launchpad", () => {
         isLinear: true,
       };
 
+      const governanceConfig = {
+        quorumPercentage: 2000, // 20%
+        approvalThreshold: 5000, // simple majority
+        votingPeriod: new anchor.BN(3 * 24 * 3600), // 3 days
+      };
+
+      const withdrawalConfig = {
+        allowWithdrawal: true,
+        penaltyPercentage: 500, // 5%
+        penaltyToCreator: false,
+        cutoffPeriod: new anchor.BN(24 * 3600), // 1 day before end
+      };
+
+      const tokenExtensions = {
+        embeddedMetadata: false,
+        metadataUri: "",
+        transferFeeBasisPoints: 0,
+        maximumFee: new anchor.BN(0),
+        nonTransferable: false,
+      };
+
       const metadata = {
         description: "Test token",
         website: "",
@@ -273,7 +738,6 @@ describe("kravtrade-launchpad", () => {
       try {
         await program.methods
           .createLaunch(
-            launchId,
             tokenName,
             tokenSymbol,
             tokenDecimals,
@@ -286,11 +750,23 @@ describe("kravtrade-launchpad", () => {
             startTime,
             endTime,
             vestingConfig,
-            metadata
+            governanceConfig,
+            withdrawalConfig,
+            metadata,
+            tokenExtensions,
+            { revoke: {} },
+            { returnToCreator: {} },
+            { disabled: {} },
+            false
           )
           .accounts({
             launchConfig: launchConfigPda,
+            launchMetadata: launchMetadataPda,
+            creatorProfile: creatorProfilePda,
+            pendingRegistry: pendingRegistryPda,
             platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            platformTreasury: treasury.publicKey,
             tokenMint: tokenMint.publicKey,
             tokenVault: tokenVault,
             creator: creator.publicKey,
@@ -307,73 +783,463 @@ describe("kravtrade-launchpad", () => {
         expect(error.message).to.include("InvalidPresaleTime");
       }
     });
-  });
-});  d
-escribe("Launch Approval", () => {
-    it("Should approve a launch", async () => {
-      await program.methods
-        .approveLaunch(launchId)
-        .accounts({
-          launchConfig: launchConfigPda,
-          platformConfig: platformConfigPda,
-          admin: admin.publicKey,
-        })
-        .signers([admin])
-        .rpc();
 
-      // Verify launch is approved
-      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
-      expect(launchConfig.status).to.deep.equal({ active: {} });
-    });
+    it("Should reject token extensions without Token-2022", async () => {
+      const currentTime = Math.floor(Date.now() / 1000);
+      const startTime = new anchor.BN(currentTime + 3600);
+      const endTime = new anchor.BN(currentTime + 7 * 24 * 3600);
+
+      tokenVault = await getAssociatedTokenAddress(
+        tokenMint.publicKey,
+        launchConfigPda,
+        true
+      );
+
+      const vestingConfig = {
+        cliffDuration: new anchor.BN(0),
+        vestingDuration: new anchor.BN(30 * 24 * 3600),
+        initialUnlockPercentage: 1000,
+        isLinear: true,
+      };
+
+      const governanceConfig = {
+        quorumPercentage: 2000, // 20%
+        approvalThreshold: 5000, // simple majority
+        votingPeriod: new anchor.BN(3 * 24 * 3600), // 3 days
+      };
+
+      const withdrawalConfig = {
+        allowWithdrawal: true,
+        penaltyPercentage: 500, // 5%
+        penaltyToCreator: false,
+        cutoffPeriod: new anchor.BN(24 * 3600), // 1 day before end
+      };
+
+      const tokenExtensions = {
+        embeddedMetadata: false,
+        metadataUri: "",
+        transferFeeBasisPoints: 100, // requires Token-2022
+        maximumFee: new anchor.BN(0),
+        nonTransferable: false,
+      };
+
+      const metadata = {
+        description: "Test token",
+        website: "",
+        twitter: "",
+        telegram: "",
+        discord: "",
+        logoUri: "",
+        documentation: "",
+      };
 
-    it("Should fail approval by non-admin", async () => {
       try {
         await program.methods
-          .approveLaunch(launchId)
+          .createLaunch(
+            tokenName,
+            tokenSymbol,
+            tokenDecimals,
+            totalSupply,
+            presalePrice,
+            minContribution,
+            maxContribution,
+            softCap,
+            hardCap,
+            startTime,
+            endTime,
+            vestingConfig,
+            governanceConfig,
+            withdrawalConfig,
+            metadata,
+            tokenExtensions,
+            { revoke: {} },
+            { returnToCreator: {} },
+            { disabled: {} },
+            false
+          )
           .accounts({
             launchConfig: launchConfigPda,
+            launchMetadata: launchMetadataPda,
+            creatorProfile: creatorProfilePda,
+            pendingRegistry: pendingRegistryPda,
             platformConfig: platformConfigPda,
-            admin: creator.publicKey, // Not the admin
+            platformStats: platformStatsPda,
+            platformTreasury: treasury.publicKey,
+            tokenMint: tokenMint.publicKey,
+            tokenVault: tokenVault,
+            creator: creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
           })
-          .signers([creator])
+          .signers([creator, tokenMint])
           .rpc();
 
-        expect.fail("Should have failed with unauthorized access");
+        expect.fail("Should have failed with invalid token extensions");
       } catch (error) {
-        expect(error.message).to.include("Unauthorized");
+        expect(error.message).to.include("InvalidTokenExtensions");
       }
     });
-  });
-
-  describe("Investor Contributions", () => {
-    it("Should allow valid contributions", async () => {
-      const contributionAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
-
-      await program.methods
-        .contribute(contributionAmount)
-        .accounts({
-          launchConfig: launchConfigPda,
-          investorAccount: investor1AccountPda,
-          platformConfig: platformConfigPda,
-          treasuryAccount: treasuryPda,
-          investor: investor1.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([investor1])
-        .rpc();
 
-      // Verify contribution
-      const investorAccount = await program.account.investorAccount.fetch(investor1AccountPda);
-      expect(investorAccount.contributionAmount.toNumber()).to.equal(contributionAmount.toNumber());
-      expect(investorAccount.investor.toString()).to.equal(investor1.publicKey.toString());
+    it("Should reject an existing mint with a freeze authority", async () => {
+      const mint = await createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        creator.publicKey,
+        tokenDecimals
+      );
 
-      // Verify launch stats updated
-      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
-      expect(launchConfig.totalRaised.toNumber()).to.equal(contributionAmount.toNumber());
-      expect(launchConfig.contributorCount).to.equal(1);
+      try {
+        await createExistingMintLaunch(mint, TOKEN_PROGRAM_ID);
+        expect.fail("Should have failed with a freeze authority");
+      } catch (error) {
+        expect(error.message).to.include("MintHasFreezeAuthority");
+      }
     });
 
-    it("Should allow multiple contributions from same investor", async () => {
+    it("Should reject an existing mint with an unsupported extension", async () => {
+      // A permanent delegate could move tokens out of the vault
+      const space = getMintLen([ExtensionType.PermanentDelegate]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: creator.publicKey,
+            newAccountPubkey: tokenMint.publicKey,
+            space,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializePermanentDelegateInstruction(
+            tokenMint.publicKey,
+            creator.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            tokenMint.publicKey,
+            tokenDecimals,
+            creator.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [creator, tokenMint]
+      );
+
+      try {
+        await createExistingMintLaunch(tokenMint.publicKey, TOKEN_2022_PROGRAM_ID);
+        expect.fail("Should have failed with an unsupported extension");
+      } catch (error) {
+        expect(error.message).to.include("UnsupportedMintExtension");
+      }
+    });
+
+    it("Should reject a non-transferable existing mint", async () => {
+      // Its tokens could never be deposited into the vault
+      const space = getMintLen([ExtensionType.NonTransferable]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: creator.publicKey,
+            newAccountPubkey: tokenMint.publicKey,
+            space,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeNonTransferableMintInstruction(tokenMint.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeMintInstruction(
+            tokenMint.publicKey,
+            tokenDecimals,
+            creator.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [creator, tokenMint]
+      );
+
+      try {
+        await createExistingMintLaunch(tokenMint.publicKey, TOKEN_2022_PROGRAM_ID);
+        expect.fail("Should have failed with an unsupported extension");
+      } catch (error) {
+        expect(error.message).to.include("UnsupportedMintExtension");
+      }
+    });
+
+    it("Should reject adding unsold tokens to a default liquidity account", async () => {
+      const currentTime = Math.floor(Date.now() / 1000);
+      const startTime = new anchor.BN(currentTime + 3600);
+      const endTime = new anchor.BN(currentTime + 7 * 24 * 3600);
+
+      tokenVault = await getAssociatedTokenAddress(
+        tokenMint.publicKey,
+        launchConfigPda,
+        true
+      );
+
+      const vestingConfig = {
+        cliffDuration: new anchor.BN(0),
+        vestingDuration: new anchor.BN(30 * 24 * 3600),
+        initialUnlockPercentage: 1000,
+        isLinear: true,
+      };
+
+      const governanceConfig = {
+        quorumPercentage: 2000, // 20%
+        approvalThreshold: 5000, // simple majority
+        votingPeriod: new anchor.BN(3 * 24 * 3600), // 3 days
+      };
+
+      const withdrawalConfig = {
+        allowWithdrawal: true,
+        penaltyPercentage: 500, // 5%
+        penaltyToCreator: false,
+        cutoffPeriod: new anchor.BN(24 * 3600), // 1 day before end
+      };
+
+      const tokenExtensions = {
+        embeddedMetadata: false,
+        metadataUri: "",
+        transferFeeBasisPoints: 0,
+        maximumFee: new anchor.BN(0),
+        nonTransferable: false,
+      };
+
+      const metadata = {
+        description: "Test token",
+        website: "",
+        twitter: "",
+        telegram: "",
+        discord: "",
+        logoUri: "",
+        documentation: "",
+      };
+
+      try {
+        await program.methods
+          .createLaunch(
+            tokenName,
+            tokenSymbol,
+            tokenDecimals,
+            totalSupply,
+            presalePrice,
+            minContribution,
+            maxContribution,
+            softCap,
+            hardCap,
+            startTime,
+            endTime,
+            vestingConfig,
+            governanceConfig,
+            withdrawalConfig,
+            metadata,
+            tokenExtensions,
+            { revoke: {} },
+            { addToLiquidity: { liquidityAccount: PublicKey.default } },
+            { disabled: {} },
+            false
+          )
+          .accounts({
+            launchConfig: launchConfigPda,
+            launchMetadata: launchMetadataPda,
+            creatorProfile: creatorProfilePda,
+            pendingRegistry: pendingRegistryPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            platformTreasury: treasury.publicKey,
+            tokenMint: tokenMint.publicKey,
+            tokenVault: tokenVault,
+            creator: creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([creator, tokenMint])
+          .rpc();
+
+        expect.fail("Should have failed with an invalid unsold token policy");
+      } catch (error) {
+        expect(error.message).to.include("InvalidUnsoldTokenPolicy");
+      }
+    });
+
+    it("Should not fund a launch that mints its own tokens", async () => {
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      const launchVault = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        launchConfigPda,
+        true
+      );
+      const creatorTokenAccount = await getAssociatedTokenAddress(
+        launchConfig.tokenMint,
+        creator.publicKey
+      );
+
+      const createAtaIx = createAssociatedTokenAccountInstruction(
+        creator.publicKey,
+        creatorTokenAccount,
+        creator.publicKey,
+        launchConfig.tokenMint
+      );
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(createAtaIx), [creator]);
+
+      try {
+        await program.methods
+          .fundLaunch(launchId, new anchor.BN(1_000))
+          .accounts({
+            launchConfig: launchConfigPda,
+            tokenMint: launchConfig.tokenMint,
+            tokenVault: launchVault,
+            creatorTokenAccount: creatorTokenAccount,
+            creator: creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have failed for a launch without an existing mint");
+      } catch (error) {
+        expect(error.message).to.include("ExistingMintRequired");
+      }
+    });
+  });
+});  d
+escribe("Launch Approval", () => {
+    it("Should reject a milestone schedule that does not release 100%", async () => {
+      const [milestoneSchedulePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("milestones"), launchId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const currentTime = Math.floor(Date.now() / 1000);
+
+      try {
+        await program.methods
+          .configureMilestones(launchId, [
+            { unlockTime: new anchor.BN(currentTime + 30 * 24 * 3600), releasePercentage: 5000 },
+            { unlockTime: new anchor.BN(currentTime + 60 * 24 * 3600), releasePercentage: 4000 },
+          ])
+          .accounts({
+            launchConfig: launchConfigPda,
+            milestoneSchedule: milestoneSchedulePda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have failed with invalid milestone schedule");
+      } catch (error) {
+        expect(error.message).to.include("InvalidMilestoneSchedule");
+      }
+    });
+
+    it("Should approve a launch", async () => {
+      await program.methods
+        .approveLaunch(launchId)
+        .accounts({
+          launchConfig: launchConfigPda,
+          platformConfig: platformConfigPda,
+          pendingRegistry: pendingRegistryPda,
+          activeRegistry: activeRegistryPda,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      // Verify launch is approved
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      expect(launchConfig.status).to.deep.equal({ active: {} });
+    });
+
+    it("Should lock metadata updates after approval", async () => {
+      const metadata = {
+        description: "Updated description after approval",
+        website: "https://test.com",
+        twitter: "@test",
+        telegram: "https://t.me/test",
+        discord: "https://discord.gg/test",
+        logoUri: "https://test.com/logo.png",
+        documentation: "https://docs.test.com",
+      };
+
+      try {
+        await program.methods
+          .updateLaunchMetadata(launchId, metadata)
+          .accounts({
+            launchConfig: launchConfigPda,
+            launchMetadata: launchMetadataPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have failed with locked metadata");
+      } catch (error) {
+        expect(error.message).to.include("MetadataLocked");
+      }
+    });
+
+    it("Should fail approval by non-admin", async () => {
+      try {
+        await program.methods
+          .approveLaunch(launchId)
+          .accounts({
+            launchConfig: launchConfigPda,
+            platformConfig: platformConfigPda,
+            pendingRegistry: pendingRegistryPda,
+            activeRegistry: activeRegistryPda,
+            admin: creator.publicKey, // Not the admin
+          })
+          .signers([creator])
+          .rpc();
+
+        expect.fail("Should have failed with unauthorized access");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Investor Contributions", () => {
+    it("Should allow valid contributions", async () => {
+      const contributionAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+
+      await program.methods
+        .contribute(contributionAmount)
+        .accounts({
+          launchConfig: launchConfigPda,
+          investorAccount: investor1AccountPda,
+          platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
+          treasuryAccount: treasuryPda,
+          referrerAccount: null,
+          contributionLedger: null,
+          investor: investor1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor1])
+        .rpc();
+
+      // Verify contribution
+      const investorAccount = await program.account.investorAccount.fetch(investor1AccountPda);
+      expect(investorAccount.contributionAmount.toNumber()).to.equal(contributionAmount.toNumber());
+      expect(investorAccount.investor.toString()).to.equal(investor1.publicKey.toString());
+
+      // Verify launch stats updated
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      expect(launchConfig.totalRaised.toNumber()).to.equal(contributionAmount.toNumber());
+      expect(launchConfig.contributorCount).to.equal(1);
+
+      const platformStats = await program.account.platformStats.fetch(platformStatsPda);
+      expect(platformStats.launchContributors.toNumber()).to.equal(1);
+    });
+
+    it("Should allow multiple contributions from same investor", async () => {
       const additionalContribution = new anchor.BN(0.3 * LAMPORTS_PER_SOL);
 
       await program.methods
@@ -382,63 +1248,294 @@ escribe("Launch Approval", () => {
           launchConfig: launchConfigPda,
           investorAccount: investor1AccountPda,
           platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
           treasuryAccount: treasuryPda,
+          referrerAccount: null,
+          contributionLedger: null,
           investor: investor1.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([investor1])
         .rpc();
 
-      // Verify total contribution
-      const investorAccount = await program.account.investorAccount.fetch(investor1AccountPda);
-      const expectedTotal = 0.5 * LAMPORTS_PER_SOL + 0.3 * LAMPORTS_PER_SOL;
-      expect(investorAccount.contributionAmount.toNumber()).to.equal(expectedTotal);
+      // Verify total contribution
+      const investorAccount = await program.account.investorAccount.fetch(investor1AccountPda);
+      const expectedTotal = 0.5 * LAMPORTS_PER_SOL + 0.3 * LAMPORTS_PER_SOL;
+      expect(investorAccount.contributionAmount.toNumber()).to.equal(expectedTotal);
+    });
+
+    it("Should reject position transfers when the launch disables them", async () => {
+      try {
+        await program.methods
+          .transferPosition(launchId, new anchor.BN(1))
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor1AccountPda,
+            recipientAccount: investor2AccountPda,
+            recipient: investor2.publicKey,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            recipientStakeAccount: null,
+            contributionLedger: null,
+            recipientLedger: null,
+            investor: investor1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor1])
+          .rpc();
+
+        expect.fail("Should have failed with transfers disabled");
+      } catch (error) {
+        expect(error.message).to.include("PositionTransferNotAllowed");
+      }
+    });
+
+    it("Should reject withdrawal above the contribution", async () => {
+      try {
+        await program.methods
+          .withdrawContribution(launchId, new anchor.BN(100 * LAMPORTS_PER_SOL))
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor1AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            penaltyRecipient: treasury.publicKey,
+            contributionLedger: null,
+            investor: investor1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor1])
+          .rpc();
+
+        expect.fail("Should have failed with insufficient funds");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientFunds");
+      }
+    });
+
+    it("Should not let another wallet withdraw a position without an NFT", async () => {
+      try {
+        await program.methods
+          .withdrawContribution(launchId, new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor1AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            penaltyRecipient: treasury.publicKey,
+            contributionLedger: null,
+            positionTokenAccount: null,
+            investor: investor2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor2])
+          .rpc();
+
+        expect.fail("Should have failed with unauthorized");
+      } catch (error) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    it("Should reject contribution below minimum", async () => {
+      const tooSmallContribution = new anchor.BN(0.005 * LAMPORTS_PER_SOL); // Below 0.01 SOL minimum
+
+      try {
+        await program.methods
+          .contribute(tooSmallContribution)
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor2AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            referrerAccount: null,
+            contributionLedger: null,
+            investor: investor2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor2])
+          .rpc();
+
+        expect.fail("Should have failed with contribution too low");
+      } catch (error) {
+        expect(error.message).to.include("ContributionTooLow");
+      }
+    });
+
+    it("Should reject contribution above maximum", async () => {
+      const tooLargeContribution = new anchor.BN(15 * LAMPORTS_PER_SOL); // Above 10 SOL maximum
+
+      try {
+        await program.methods
+          .contribute(tooLargeContribution)
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor2AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            referrerAccount: null,
+            contributionLedger: null,
+            investor: investor2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor2])
+          .rpc();
+
+        expect.fail("Should have failed with contribution too high");
+      } catch (error) {
+        expect(error.message).to.include("ContributionTooHigh");
+      }
+    });
+
+    it("Should reject self-referral", async () => {
+      const [investor1ReferrerPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("referrer"),
+          launchId.toArrayLike(Buffer, "le", 8),
+          investor1.publicKey.toBuffer()
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .registerReferrer(launchId)
+        .accounts({
+          launchConfig: launchConfigPda,
+          referrerAccount: investor1ReferrerPda,
+          referrer: investor1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([investor1])
+        .rpc();
+
+      try {
+        await program.methods
+          .contribute(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor1AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            referrerAccount: investor1ReferrerPda,
+            contributionLedger: null,
+            investor: investor1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor1])
+          .rpc();
+
+        expect.fail("Should have failed with self-referral");
+      } catch (error) {
+        expect(error.message).to.include("SelfReferral");
+      }
     });
 
-    it("Should reject contribution below minimum", async () => {
-      const tooSmallContribution = new anchor.BN(0.005 * LAMPORTS_PER_SOL); // Below 0.01 SOL minimum
+    describe("Referral attribution", () => {
+      const referrerPda = () => launchPda("referrer", launchId, investor1.publicKey);
 
-      try {
+      const withdraw = (amount: anchor.BN, referrerAccount: PublicKey | null) =>
+        program.methods
+          .withdrawContribution(launchId, amount)
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor2AccountPda,
+            platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
+            treasuryAccount: treasuryPda,
+            penaltyRecipient: treasury.publicKey,
+            referrerAccount: referrerAccount,
+            contributionLedger: null,
+            positionTokenAccount: null,
+            investor: investor2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([investor2])
+          .rpc();
+
+      it("Should attribute a referred contribution to the position", async () => {
         await program.methods
-          .contribute(tooSmallContribution)
+          .contribute(new anchor.BN(0.2 * LAMPORTS_PER_SOL))
           .accounts({
             launchConfig: launchConfigPda,
             investorAccount: investor2AccountPda,
             platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
             treasuryAccount: treasuryPda,
+            referrerAccount: referrerPda(),
+            stakeAccount: null,
+            contributionLedger: null,
             investor: investor2.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .signers([investor2])
           .rpc();
 
-        expect.fail("Should have failed with contribution too low");
-      } catch (error) {
-        expect(error.message).to.include("ContributionTooLow");
-      }
-    });
+        const investorAccount = await program.account.investorAccount.fetch(investor2AccountPda);
+        expect(investorAccount.referrer.toString()).to.equal(investor1.publicKey.toString());
+        expect(investorAccount.referredAmount.toNumber()).to.equal(0.2 * LAMPORTS_PER_SOL);
 
-    it("Should reject contribution above maximum", async () => {
-      const tooLargeContribution = new anchor.BN(15 * LAMPORTS_PER_SOL); // Above 10 SOL maximum
+        const referrer = await program.account.referrer.fetch(referrerPda());
+        expect(referrer.referredVolume.toNumber()).to.equal(0.2 * LAMPORTS_PER_SOL);
+
+        const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+        expect(launchConfig.contributorCount).to.equal(2);
+      });
+
+      it("Should require the referrer account to withdraw referred contributions", async () => {
+        try {
+          await withdraw(new anchor.BN(0.1 * LAMPORTS_PER_SOL), null);
+
+          expect.fail("Should have failed without the referrer account");
+        } catch (error) {
+          expect(error.message).to.include("ReferrerAccountMissing");
+        }
+      });
+
+      it("Should reverse the attribution pro-rata on withdrawal", async () => {
+        await withdraw(new anchor.BN(0.1 * LAMPORTS_PER_SOL), referrerPda());
+
+        const investorAccount = await program.account.investorAccount.fetch(investor2AccountPda);
+        expect(investorAccount.referredAmount.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+
+        const referrer = await program.account.referrer.fetch(referrerPda());
+        expect(referrer.referredVolume.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+
+        const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+        expect(launchConfig.referredVolume.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+      });
+
+      it("Should count an investor again after withdrawing everything", async () => {
+        await withdraw(new anchor.BN(0.1 * LAMPORTS_PER_SOL), referrerPda());
+
+        let launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+        expect(launchConfig.contributorCount).to.equal(1);
+        expect(launchConfig.referredVolume.toNumber()).to.equal(0);
 
-      try {
         await program.methods
-          .contribute(tooLargeContribution)
+          .contribute(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
           .accounts({
             launchConfig: launchConfigPda,
             investorAccount: investor2AccountPda,
             platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
             treasuryAccount: treasuryPda,
+            referrerAccount: null,
+            stakeAccount: null,
+            contributionLedger: null,
             investor: investor2.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .signers([investor2])
           .rpc();
 
-        expect.fail("Should have failed with contribution too high");
-      } catch (error) {
-        expect(error.message).to.include("ContributionTooHigh");
-      }
+        launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+        expect(launchConfig.contributorCount).to.equal(2);
+      });
     });
   });
 
@@ -453,13 +1550,26 @@ escribe("Launch Approval", () => {
           launchConfig: launchConfigPda,
           investorAccount: investor2AccountPda,
           platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
           treasuryAccount: treasuryPda,
+          referrerAccount: null,
+          contributionLedger: investor2LedgerPda,
           investor: investor2.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([investor2])
         .rpc();
 
+      // The contribution started investor 2's ledger
+      const ledger = await program.account.contributionLedger.fetch(investor2LedgerPda);
+      expect(ledger.entries.length).to.equal(1);
+      expect(ledger.entries[0].kind).to.deep.equal({ contribution: {} });
+      expect(ledger.entries[0].amount.toNumber()).to.equal(additionalContribution.toNumber());
+      expect(ledger.entries[0].slot.toNumber()).to.be.greaterThan(0);
+
+      const investorAccount = await program.account.investorAccount.fetch(investor2AccountPda);
+      expect(investorAccount.ledgerEntries.toNumber()).to.equal(1);
+
       // Wait for presale to end (simulate time passage)
       // In real test, you would manipulate clock or wait
 
@@ -469,26 +1579,54 @@ escribe("Launch Approval", () => {
         launchConfigPda,
         true
       );
+      // Receives the creator allocation (total supply beyond the hard cap allocation)
+      const creatorTokenAccount = await getAssociatedTokenAddress(
+        tokenMint.publicKey,
+        creator.publicKey
+      );
 
+      const finalizeAccounts = {
+        launchConfig: launchConfigPda,
+        platformConfig: platformConfigPda,
+        platformStats: platformStatsPda,
+        tokenMint: tokenMint.publicKey,
+        tokenVault: tokenVault,
+        creatorTokenAccount: creatorTokenAccount,
+        liquidityTokenAccount: null,
+        treasuryAccount: treasuryPda,
+        platformTreasury: treasury.publicKey,
+        insuranceFund: insuranceFundPda,
+        creatorProfile: creatorProfilePda,
+        activeRegistry: activeRegistryPda,
+        endedRegistry: endedRegistryPda,
+        milestoneSchedule: null,
+        payoutAccount: creator.publicKey,
+        creator: creator.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
       await program.methods
         .finalizeLaunch(launchId)
-        .accounts({
-          launchConfig: launchConfigPda,
-          platformConfig: platformConfigPda,
-          tokenMint: tokenMint.publicKey,
-          tokenVault: tokenVault,
-          treasuryAccount: treasuryPda,
-          platformTreasury: treasury.publicKey,
-          creator: creator.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
+        .accounts(finalizeAccounts)
         .signers([creator])
         .rpc();
 
       // Verify launch is finalized
       const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
       expect(launchConfig.status).to.deep.equal({ successful: {} });
+      expect(launchConfig.finalized).to.be.true;
+
+      // Fees and proceeds are paid out only once
+      try {
+        await program.methods
+          .finalizeLaunch(launchId)
+          .accounts(finalizeAccounts)
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have failed with the launch already finalized");
+      } catch (error) {
+        expect(error.message).to.include("LaunchAlreadyFinalized");
+      }
     });
   });
 
@@ -521,10 +1659,14 @@ escribe("Launch Approval", () => {
         .accounts({
           launchConfig: launchConfigPda,
           investorAccount: investor1AccountPda,
+          tokenMint: tokenMint.publicKey,
           tokenVault: tokenVault,
           investorTokenAccount: investorTokenAccount,
+          platformStats: platformStatsPda,
+          contributionLedger: null,
           investor: investor1.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([investor1])
         .rpc();
@@ -533,6 +1675,270 @@ escribe("Launch Approval", () => {
       const investorAccount = await program.account.investorAccount.fetch(investor1AccountPda);
       expect(investorAccount.claimedAmount.toNumber()).to.be.greaterThan(0);
     });
+
+    it("Should not close an investor account with unclaimed tokens", async () => {
+      try {
+        await program.methods
+          .closeInvestorAccount(launchId)
+          .accounts({
+            launchConfig: launchConfigPda,
+            investorAccount: investor1AccountPda,
+            milestoneSchedule: null,
+            insuranceClaim: PublicKey.findProgramAddressSync(
+              [Buffer.from("insurance_claim"), launchId.toArrayLike(Buffer, "le", 8)],
+              program.programId
+            )[0],
+            investor: investor1.publicKey,
+          })
+          .signers([investor1])
+          .rpc();
+
+        expect.fail("Should have failed with unsettled position");
+      } catch (error) {
+        expect(error.message).to.include("PositionNotSettled");
+      }
+    });
+  });
+
+  describe("Refund Crank", () => {
+    const refundLaunchId = new anchor.BN(2);
+    let refundLaunch: Awaited<ReturnType<typeof createApprovedLaunch>>;
+
+    const processRefunds = (remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]) =>
+      program.methods
+        .processRefunds(refundLaunchId)
+        .accounts({
+          launchConfig: refundLaunch.launchConfig,
+          treasuryAccount: refundLaunch.treasuryAccount,
+          platformStats: platformStatsPda,
+          processor: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([admin])
+        .rpc();
+
+    // The program ID stands in for the ledger page of investors who keep no ledger,
+    // and for the position NFT token account of positions without an NFT
+    const refundAccounts = (investorAccount: PublicKey, investor: PublicKey, ledger?: PublicKey) => [
+      { pubkey: investorAccount, isSigner: false, isWritable: true },
+      { pubkey: investor, isSigner: false, isWritable: true },
+      { pubkey: ledger ?? program.programId, isSigner: false, isWritable: !!ledger },
+      { pubkey: program.programId, isSigner: false, isWritable: false },
+    ];
+
+    before(async () => {
+      refundLaunch = await createApprovedLaunch(refundLaunchId);
+      await contributeTo(refundLaunchId, investor1, new anchor.BN(0.2 * LAMPORTS_PER_SOL));
+      await contributeTo(
+        refundLaunchId,
+        investor2,
+        new anchor.BN(0.3 * LAMPORTS_PER_SOL),
+        ledgerPage(refundLaunchId, investor2.publicKey, 0)
+      );
+
+      await program.methods
+        .cancelLaunch(refundLaunchId)
+        .accounts({
+          launchConfig: refundLaunch.launchConfig,
+          platformConfig: platformConfigPda,
+          platformStats: platformStatsPda,
+          platformTreasury: treasury.publicKey,
+          activeRegistry: activeRegistryPda,
+          endedRegistry: endedRegistryPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Should reject an incomplete investor tuple", async () => {
+      try {
+        await processRefunds([
+          ...refundAccounts(launchPda("investor", refundLaunchId, investor1.publicKey), investor1.publicKey),
+          { pubkey: launchPda("investor", refundLaunchId, investor2.publicKey), isSigner: false, isWritable: true },
+        ]);
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should reject an investor account of another launch", async () => {
+      try {
+        await processRefunds(refundAccounts(investor1AccountPda, investor1.publicKey));
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should reject a tuple paying a different wallet", async () => {
+      try {
+        await processRefunds(
+          refundAccounts(launchPda("investor", refundLaunchId, investor1.publicKey), investor2.publicKey)
+        );
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should refund a batch and skip investors already refunded", async () => {
+      const investor1Account = launchPda("investor", refundLaunchId, investor1.publicKey);
+      const investor2Account = launchPda("investor", refundLaunchId, investor2.publicKey);
+      const investor2Ledger = ledgerPage(refundLaunchId, investor2.publicKey, 0);
+
+      // Investor 1 is refunded by an earlier batch
+      await processRefunds(refundAccounts(investor1Account, investor1.publicKey));
+
+      const investor1Before = await provider.connection.getBalance(investor1.publicKey);
+      const investor2Before = await provider.connection.getBalance(investor2.publicKey);
+
+      await processRefunds([
+        ...refundAccounts(investor1Account, investor1.publicKey),
+        ...refundAccounts(investor2Account, investor2.publicKey, investor2Ledger),
+      ]);
+
+      expect(await provider.connection.getBalance(investor1.publicKey)).to.equal(investor1Before);
+      expect(await provider.connection.getBalance(investor2.publicKey)).to.equal(
+        investor2Before + 0.3 * LAMPORTS_PER_SOL
+      );
+      const refunded = await program.account.investorAccount.fetch(investor2Account);
+      expect(refunded.isRefunded).to.be.true;
+
+      // The refund is recorded in investor 2's ledger
+      const ledger = await program.account.contributionLedger.fetch(investor2Ledger);
+      expect(ledger.entries.length).to.equal(2);
+      expect(ledger.entries[1].kind).to.deep.equal({ refund: {} });
+      expect(ledger.entries[1].amount.toNumber()).to.equal(0.3 * LAMPORTS_PER_SOL);
+
+      // Retrying the batch pays nobody twice
+      await processRefunds(refundAccounts(investor2Account, investor2.publicKey, investor2Ledger));
+      expect(await provider.connection.getBalance(investor2.publicKey)).to.equal(
+        investor2Before + 0.3 * LAMPORTS_PER_SOL
+      );
+    });
+  });
+
+  describe("Distribution Crank", () => {
+    let tokenMint: PublicKey;
+
+    const distributeTokens = (remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]) =>
+      program.methods
+        .distributeTokens(launchId)
+        .accounts({
+          launchConfig: launchConfigPda,
+          tokenMint: tokenMint,
+          tokenVault: getAssociatedTokenAddressSync(tokenMint, launchConfigPda, true),
+          platformStats: platformStatsPda,
+          distributor: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([admin])
+        .rpc();
+
+    // The program ID stands in for the ledger page of investors who keep no ledger,
+    // and for the position NFT token account of positions without an NFT
+    const distributionAccounts = (
+      investorAccount: PublicKey,
+      investor: PublicKey,
+      ledger?: PublicKey,
+      tokenAccount?: PublicKey
+    ) => [
+      { pubkey: investorAccount, isSigner: false, isWritable: true },
+      { pubkey: investor, isSigner: false, isWritable: true },
+      {
+        pubkey: tokenAccount ?? getAssociatedTokenAddressSync(tokenMint, investor),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: ledger ?? program.programId, isSigner: false, isWritable: !!ledger },
+      { pubkey: program.programId, isSigner: false, isWritable: false },
+    ];
+
+    before(async () => {
+      const launchConfig = await program.account.launchConfig.fetch(launchConfigPda);
+      tokenMint = launchConfig.tokenMint;
+    });
+
+    it("Should reject an incomplete investor tuple", async () => {
+      try {
+        await distributeTokens(distributionAccounts(investor1AccountPda, investor1.publicKey).slice(0, 4));
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should reject an investor account of another launch", async () => {
+      try {
+        await distributeTokens(
+          distributionAccounts(launchPda("investor", new anchor.BN(2), investor1.publicKey), investor1.publicKey)
+        );
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should reject a token account that is not the investor's", async () => {
+      try {
+        await distributeTokens(
+          distributionAccounts(
+            investor1AccountPda,
+            investor1.publicKey,
+            undefined,
+            getAssociatedTokenAddressSync(tokenMint, investor2.publicKey)
+          )
+        );
+
+        expect.fail("Should have failed with invalid remaining accounts");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Should skip investors who already claimed everything vested", async () => {
+      const beforeCrank = await program.account.investorAccount.fetch(investor1AccountPda);
+
+      // Investor 1 claimed the initial unlock and the cliff has not passed
+      await distributeTokens(distributionAccounts(investor1AccountPda, investor1.publicKey));
+
+      const afterCrank = await program.account.investorAccount.fetch(investor1AccountPda);
+      expect(afterCrank.claimedAmount.toNumber()).to.equal(beforeCrank.claimedAmount.toNumber());
+    });
+
+    it("Should require the ledger page of an investor keeping a ledger", async () => {
+      try {
+        await distributeTokens(distributionAccounts(investor2AccountPda, investor2.publicKey));
+
+        expect.fail("Should have failed with the ledger page missing");
+      } catch (error) {
+        expect(error.message).to.include("ContributionLedgerMissing");
+      }
+    });
+
+    it("Should record distributed tokens in the investor's ledger", async () => {
+      await distributeTokens(distributionAccounts(investor2AccountPda, investor2.publicKey, investor2LedgerPda));
+
+      const investorAccount = await program.account.investorAccount.fetch(investor2AccountPda);
+      expect(investorAccount.claimedAmount.toNumber()).to.be.greaterThan(0);
+
+      const ledger = await program.account.contributionLedger.fetch(investor2LedgerPda);
+      expect(ledger.entries.length).to.equal(2);
+      expect(ledger.entries[1].kind).to.deep.equal({ claim: {} });
+      expect(ledger.entries[1].amount.toNumber()).to.equal(investorAccount.claimedAmount.toNumber());
+    });
   });
 
   describe("Emergency Controls", () => {
@@ -562,7 +1968,10 @@ escribe("Launch Approval", () => {
             launchConfig: launchConfigPda,
             investorAccount: investor2AccountPda,
             platformConfig: platformConfigPda,
+            platformStats: platformStatsPda,
             treasuryAccount: treasuryPda,
+            referrerAccount: null,
+            contributionLedger: null,
             investor: investor2.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -577,16 +1986,27 @@ escribe("Launch Approval", () => {
   });
 
   describe("Platform Configuration Updates", () => {
+    // Settings left out of an update keep their current value
+    const configUpdate = (update: object) => ({
+      platformFeePercentage: null,
+      minLaunchDuration: null,
+      maxLaunchDuration: null,
+      minSoftCap: null,
+      listingFee: null,
+      creatorBondAmount: null,
+      referralFeeShare: null,
+      insuranceFeeShare: null,
+      milestoneThreshold: null,
+      refundWindow: null,
+      lastLaunchId: null,
+      ...update,
+    });
+
     it("Should allow admin to update platform config", async () => {
       const newFeePercentage = 300; // 3%
 
       await program.methods
-        .updatePlatformConfig(
-          newFeePercentage,
-          null,
-          null,
-          null
-        )
+        .updatePlatformConfig(configUpdate({ platformFeePercentage: newFeePercentage }))
         .accounts({
           platformConfig: platformConfigPda,
           admin: admin.publicKey,
@@ -599,15 +2019,28 @@ escribe("Launch Approval", () => {
       expect(platformConfig.platformFeePercentage).to.equal(newFeePercentage);
     });
 
+    it("Should allow admin to set listing fee and creator bond", async () => {
+      const listingFee = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const creatorBond = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+
+      await program.methods
+        .updatePlatformConfig(configUpdate({ listingFee, creatorBondAmount: creatorBond }))
+        .accounts({
+          platformConfig: platformConfigPda,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const platformConfig = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(platformConfig.listingFee.toNumber()).to.equal(listingFee.toNumber());
+      expect(platformConfig.creatorBondAmount.toNumber()).to.equal(creatorBond.toNumber());
+    });
+
     it("Should reject config update by non-admin", async () => {
       try {
         await program.methods
-          .updatePlatformConfig(
-            400,
-            null,
-            null,
-            null
-          )
+          .updatePlatformConfig(configUpdate({ platformFeePercentage: 400 }))
           .accounts({
             platformConfig: platformConfigPda,
             admin: creator.publicKey, // Not admin
@@ -621,4 +2054,213 @@ escribe("Launch Approval", () => {
       }
     });
   });
-});
\ No newline at end of file
+
+  describe("Staking Tiers", () => {
+    let stakeMint: PublicKey;
+    let stakeVault: PublicKey;
+
+    before(async () => {
+      stakeMint = await createMint(provider.connection, admin, admin.publicKey, null, 9);
+      stakeVault = await getAssociatedTokenAddress(stakeMint, platformConfigPda, true);
+    });
+
+    const tier = (minStake: number, multiplier: number) => ({
+      minStake: new anchor.BN(minStake),
+      minLockDuration: new anchor.BN(0),
+      allocationMultiplier: multiplier,
+      guaranteedAllocation: new anchor.BN(0),
+    });
+
+    it("Should reject tiers out of stake order", async () => {
+      try {
+        await program.methods
+          .configureStaking([tier(2_000, 20_000), tier(1_000, 15_000)])
+          .accounts({
+            platformConfig: platformConfigPda,
+            stakeMint: stakeMint,
+            stakeVault: stakeVault,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed with invalid tiers");
+      } catch (error) {
+        expect(error.message).to.include("InvalidStakingTiers");
+      }
+    });
+
+    it("Should allow admin to configure staking tiers", async () => {
+      await program.methods
+        .configureStaking([tier(1_000, 15_000), tier(2_000, 20_000)])
+        .accounts({
+          platformConfig: platformConfigPda,
+          stakeMint: stakeMint,
+          stakeVault: stakeVault,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const platformConfig = await program.account.platformConfig.fetch(platformConfigPda);
+      expect(platformConfig.stakeMint.toString()).to.equal(stakeMint.toString());
+      expect(platformConfig.stakingTiers.length).to.equal(2);
+      expect(platformConfig.stakingTiers[1].allocationMultiplier).to.equal(20_000);
+    });
+
+    describe("Guaranteed allocations", () => {
+      const reserveLaunchId = new anchor.BN(3);
+      const saleMaxContribution = new anchor.BN(5 * LAMPORTS_PER_SOL);
+      const guaranteed = new anchor.BN(4 * LAMPORTS_PER_SOL);
+      const staker = Keypair.generate();
+      const buyer1 = Keypair.generate();
+      const buyer2 = Keypair.generate();
+      const sol = (amount: number) => new anchor.BN(amount * LAMPORTS_PER_SOL);
+      let reserveLaunch: Awaited<ReturnType<typeof createApprovedLaunch>>;
+      let stakeAccountPda: PublicKey;
+
+      const reserveAllocation = () =>
+        program.methods
+          .reserveAllocation(reserveLaunchId)
+          .accounts({
+            launchConfig: reserveLaunch.launchConfig,
+            investorAccount: launchPda("investor", reserveLaunchId, staker.publicKey),
+            platformConfig: platformConfigPda,
+            stakeAccount: stakeAccountPda,
+            investor: staker.publicKey,
+          })
+          .signers([staker])
+          .rpc();
+
+      before(async () => {
+        for (const wallet of [staker, buyer1, buyer2]) {
+          await provider.connection.confirmTransaction(
+            await provider.connection.requestAirdrop(wallet.publicKey, 10 * LAMPORTS_PER_SOL)
+          );
+        }
+
+        // Tier 1 may contribute 1.5x the launch maximum and is guaranteed 4 SOL
+        await program.methods
+          .configureStaking([{ ...tier(1_000, 15_000), guaranteedAllocation: guaranteed }])
+          .accounts({
+            platformConfig: platformConfigPda,
+            stakeMint: stakeMint,
+            stakeVault: stakeVault,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        const stakerTokenAccount = await createAssociatedTokenAccount(
+          provider.connection,
+          staker,
+          stakeMint,
+          staker.publicKey
+        );
+        await mintTo(provider.connection, admin, stakeMint, stakerTokenAccount, admin, 1_000);
+
+        [stakeAccountPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("stake"), staker.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .stake(new anchor.BN(1_000), new anchor.BN(8 * 24 * 3600))
+          .accounts({
+            platformConfig: platformConfigPda,
+            stakeAccount: stakeAccountPda,
+            stakeMint: stakeMint,
+            stakeVault: stakeVault,
+            stakerTokenAccount: stakerTokenAccount,
+            staker: staker.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([staker])
+          .rpc();
+
+        reserveLaunch = await createApprovedLaunch(reserveLaunchId, saleMaxContribution);
+      });
+
+      it("Should reserve a staker's guaranteed allocation", async () => {
+        await reserveAllocation();
+
+        const launchConfig = await program.account.launchConfig.fetch(reserveLaunch.launchConfig);
+        expect(launchConfig.reservedAllocation.toString()).to.equal(guaranteed.toString());
+        const stakeAccount = await program.account.stakeAccount.fetch(stakeAccountPda);
+        expect(stakeAccount.reservedLaunchId.toNumber()).to.equal(reserveLaunchId.toNumber());
+        expect(stakeAccount.reservedAllocation.toString()).to.equal(guaranteed.toString());
+        expect(stakeAccount.reservationExpiresAt.toNumber()).to.equal(
+          launchConfig.endTime.toNumber() - 12 * 3600
+        );
+
+        // A reservation alone opens no investor account
+        const investorAccount = await provider.connection.getAccountInfo(
+          launchPda("investor", reserveLaunchId, staker.publicKey)
+        );
+        expect(investorAccount).to.be.null;
+
+        try {
+          await reserveAllocation();
+          expect.fail("Should have failed with nothing left to reserve");
+        } catch (error) {
+          expect(error.message).to.include("NoAllocationToReserve");
+        }
+      });
+
+      it("Should keep reserved allocation out of other contributions", async () => {
+        // 10 SOL hard cap with 4 SOL reserved leaves 1 SOL after buyer1's 5 SOL
+        await contributeTo(reserveLaunchId, buyer1, sol(5));
+
+        try {
+          await contributeTo(reserveLaunchId, buyer2, sol(2));
+          expect.fail("Should have failed with the hard cap exceeded");
+        } catch (error) {
+          expect(error.message).to.include("HardCapExceeded");
+        }
+      });
+
+      it("Should cap an investor's running total of contributions", async () => {
+        // buyer1 has contributed the launch maximum already
+        try {
+          await contributeTo(reserveLaunchId, buyer1, sol(1));
+          expect.fail("Should have failed with the contribution too high");
+        } catch (error) {
+          expect(error.message).to.include("ContributionTooHigh");
+        }
+
+        // The staker's tier allows 7.5 SOL in total
+        await contributeTo(reserveLaunchId, staker, sol(2), null, stakeAccountPda);
+        try {
+          await contributeTo(reserveLaunchId, staker, sol(6), null, stakeAccountPda);
+          expect.fail("Should have failed with the tier limit exceeded");
+        } catch (error) {
+          expect(error.message).to.include("ContributionTooHigh");
+        }
+      });
+
+      it("Should use up the reservation when the staker contributes", async () => {
+        // 2 SOL of the reservation are left, plus the 1 SOL nobody reserved
+        await contributeTo(reserveLaunchId, staker, sol(3), null, stakeAccountPda);
+
+        const launchConfig = await program.account.launchConfig.fetch(reserveLaunch.launchConfig);
+        expect(launchConfig.totalRaised.toString()).to.equal(hardCap.toString());
+        expect(launchConfig.reservedAllocation.toNumber()).to.equal(0);
+        const investorAccount = await program.account.investorAccount.fetch(
+          launchPda("investor", reserveLaunchId, staker.publicKey)
+        );
+        expect(investorAccount.contributionAmount.toString()).to.equal(sol(5).toString());
+        const stakeAccount = await program.account.stakeAccount.fetch(stakeAccountPda);
+        expect(stakeAccount.reservedAllocation.toNumber()).to.equal(0);
+      });
+    });
+  });
+});